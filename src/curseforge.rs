@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -223,18 +225,18 @@ pub struct Mod {
     pub sync_at: DateTime<Utc>,
 }
 
-// #[serde_as]
-// #[derive(Debug, Deserialize, Clone)]
-// pub struct Fingerprint {
-//     #[serde(alias = "_id")]
-//     pub id: i64,
-//     pub file: FileInfo,
-//     #[serde(rename = "latestFiles")]
-//     pub latest_files: Vec<FileInfo>,
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Fingerprint {
+    #[serde(alias = "_id")]
+    pub id: i64,
+    pub file: FileInfo,
+    #[serde(rename = "latestFiles")]
+    pub latest_files: Vec<FileInfo>,
 
-//     #[serde_as(as = "FromChrono04DateTime")]
-//     pub sync_at: DateTime<Utc>,
-// }
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
 
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -338,6 +340,679 @@ pub struct FileIndex {
     pub mod_loader: Option<i32>,
 }
 
+impl File {
+    /// This file as `latest_files_indexes` entries: one per Minecraft
+    /// version and listed loader, or without a loader when it lists none.
+    pub fn indexes(&self) -> Vec<FileIndex> {
+        let tags = self.game_version_tags();
+        let loaders: Vec<Option<i32>> = if tags.loaders.is_empty() {
+            vec![None]
+        } else {
+            tags.loaders.iter().map(|l| Some(l.id())).collect()
+        };
+        tags.minecraft
+            .iter()
+            .flat_map(|version| {
+                loaders.iter().map(move |loader| FileIndex {
+                    game_version: Some(version.clone()),
+                    file_id: self.id,
+                    filename: self.file_name.clone(),
+                    release_type: self.release_type,
+                    game_version_type_id: None,
+                    mod_loader: *loader,
+                })
+            })
+            .collect()
+    }
+
+    pub fn from_info(info: FileInfo, sync_at: DateTime<Utc>) -> Self {
+        let FileInfo {
+            id,
+            game_id,
+            mod_id,
+            is_available,
+            display_name,
+            file_name,
+            release_type,
+            file_status,
+            hashes,
+            file_date,
+            file_length,
+            download_count,
+            file_size_on_disk,
+            download_url,
+            game_versions,
+            sortable_game_versions,
+            dependencies,
+            expose_as_alternative,
+            parent_project_file_id,
+            alternate_file_id,
+            is_server_pack,
+            server_pack_file_id,
+            is_early_access_content,
+            early_access_end_date,
+            file_fingerprint,
+            modules,
+        } = info;
+        File {
+            id,
+            game_id,
+            mod_id,
+            is_available,
+            display_name,
+            file_name,
+            release_type,
+            file_status,
+            hashes,
+            file_date,
+            file_length,
+            download_count,
+            file_size_on_disk,
+            download_url,
+            game_versions,
+            sortable_game_versions,
+            dependencies,
+            expose_as_alternative,
+            parent_project_file_id,
+            alternate_file_id,
+            is_server_pack,
+            server_pack_file_id,
+            is_early_access_content,
+            early_access_end_date,
+            file_fingerprint,
+            modules,
+            sync_at,
+        }
+    }
+
+    pub fn info(&self) -> FileInfo {
+        FileInfo::from(self.clone())
+    }
+}
+
+impl From<File> for FileInfo {
+    fn from(file: File) -> Self {
+        let File {
+            id,
+            game_id,
+            mod_id,
+            is_available,
+            display_name,
+            file_name,
+            release_type,
+            file_status,
+            hashes,
+            file_date,
+            file_length,
+            download_count,
+            file_size_on_disk,
+            download_url,
+            game_versions,
+            sortable_game_versions,
+            dependencies,
+            expose_as_alternative,
+            parent_project_file_id,
+            alternate_file_id,
+            is_server_pack,
+            server_pack_file_id,
+            is_early_access_content,
+            early_access_end_date,
+            file_fingerprint,
+            modules,
+            sync_at: _,
+        } = file;
+        FileInfo {
+            id,
+            game_id,
+            mod_id,
+            is_available,
+            display_name,
+            file_name,
+            release_type,
+            file_status,
+            hashes,
+            file_date,
+            file_length,
+            download_count,
+            file_size_on_disk,
+            download_url,
+            game_versions,
+            sortable_game_versions,
+            dependencies,
+            expose_as_alternative,
+            parent_project_file_id,
+            alternate_file_id,
+            is_server_pack,
+            server_pack_file_id,
+            is_early_access_content,
+            early_access_end_date,
+            file_fingerprint,
+            modules,
+        }
+    }
+}
+
+impl Mod {
+    /// Standalone file records for `latest_files`, owned by this mod.
+    pub fn to_files(&self) -> Vec<File> {
+        self.latest_files
+            .iter()
+            .flatten()
+            .map(|info| {
+                let mut file = File::from_info(info.clone(), self.sync_at);
+                file.mod_id = self.id;
+                file
+            })
+            .collect()
+    }
+
+    pub fn latest_file(&self, file_id: i32) -> Option<&FileInfo> {
+        self.latest_files.iter().flatten().find(|f| f.id == file_id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModLoaderType {
+    Any,
+    Forge,
+    Cauldron,
+    LiteLoader,
+    Fabric,
+    Quilt,
+    NeoForge,
+}
+
+impl ModLoaderType {
+    pub fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(ModLoaderType::Any),
+            1 => Some(ModLoaderType::Forge),
+            2 => Some(ModLoaderType::Cauldron),
+            3 => Some(ModLoaderType::LiteLoader),
+            4 => Some(ModLoaderType::Fabric),
+            5 => Some(ModLoaderType::Quilt),
+            6 => Some(ModLoaderType::NeoForge),
+            _ => None,
+        }
+    }
+
+    pub fn id(&self) -> i32 {
+        match self {
+            ModLoaderType::Any => 0,
+            ModLoaderType::Forge => 1,
+            ModLoaderType::Cauldron => 2,
+            ModLoaderType::LiteLoader => 3,
+            ModLoaderType::Fabric => 4,
+            ModLoaderType::Quilt => 5,
+            ModLoaderType::NeoForge => 6,
+        }
+    }
+
+    /// Lowercase name, matching Modrinth's loader tags.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ModLoaderType::Any => "any",
+            ModLoaderType::Forge => "forge",
+            ModLoaderType::Cauldron => "cauldron",
+            ModLoaderType::LiteLoader => "liteloader",
+            ModLoaderType::Fabric => "fabric",
+            ModLoaderType::Quilt => "quilt",
+            ModLoaderType::NeoForge => "neoforge",
+        }
+    }
+
+    /// Parses a loader name case-insensitively, as found in `gameVersions`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "forge" => Some(ModLoaderType::Forge),
+            "cauldron" => Some(ModLoaderType::Cauldron),
+            "liteloader" => Some(ModLoaderType::LiteLoader),
+            "fabric" => Some(ModLoaderType::Fabric),
+            "quilt" => Some(ModLoaderType::Quilt),
+            "neoforge" => Some(ModLoaderType::NeoForge),
+            _ => None,
+        }
+    }
+}
+
+impl FileIndex {
+    pub fn loader(&self) -> Option<ModLoaderType> {
+        self.mod_loader.and_then(ModLoaderType::from_id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelationType {
+    EmbeddedLibrary,
+    OptionalDependency,
+    RequiredDependency,
+    Tool,
+    Incompatible,
+    Include,
+}
+
+impl RelationType {
+    pub fn from_id(id: i32) -> Option<Self> {
+        match id {
+            1 => Some(RelationType::EmbeddedLibrary),
+            2 => Some(RelationType::OptionalDependency),
+            3 => Some(RelationType::RequiredDependency),
+            4 => Some(RelationType::Tool),
+            5 => Some(RelationType::Incompatible),
+            6 => Some(RelationType::Include),
+            _ => None,
+        }
+    }
+
+    pub fn id(&self) -> i32 {
+        match self {
+            RelationType::EmbeddedLibrary => 1,
+            RelationType::OptionalDependency => 2,
+            RelationType::RequiredDependency => 3,
+            RelationType::Tool => 4,
+            RelationType::Incompatible => 5,
+            RelationType::Include => 6,
+        }
+    }
+}
+
+/// CurseForge's file fingerprint: 32-bit MurmurHash2 with seed 1 over the
+/// file contents with tabs, newlines, carriage returns and spaces removed.
+pub fn murmur2_fingerprint(data: &[u8]) -> u32 {
+    const M: u32 = 0x5bd1e995;
+    let bytes: Vec<u8> = data
+        .iter()
+        .copied()
+        .filter(|b| !matches!(b, 9 | 10 | 13 | 32))
+        .collect();
+
+    let mut h = 1 ^ bytes.len() as u32;
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M) ^ k;
+    }
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        for (i, b) in rest.iter().enumerate() {
+            h ^= (*b as u32) << (8 * i);
+        }
+        h = h.wrapping_mul(M);
+    }
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^ (h >> 15)
+}
+
+impl FileDependencies {
+    pub fn relation(&self) -> Option<RelationType> {
+        self.relation_type.and_then(RelationType::from_id)
+    }
+}
+
+impl Hash {
+    pub const SHA1: i32 = 1;
+    pub const MD5: i32 = 2;
+}
+
+fn find_hash(hashes: &Option<Vec<Hash>>, algo: i32) -> Option<&str> {
+    hashes
+        .iter()
+        .flatten()
+        .find(|h| h.algo == algo)
+        .map(|h| h.value.as_str())
+}
+
+impl File {
+    pub fn sha1(&self) -> Option<&str> {
+        find_hash(&self.hashes, Hash::SHA1)
+    }
+
+    pub fn md5(&self) -> Option<&str> {
+        find_hash(&self.hashes, Hash::MD5)
+    }
+}
+
+impl FileInfo {
+    pub fn sha1(&self) -> Option<&str> {
+        find_hash(&self.hashes, Hash::SHA1)
+    }
+
+    pub fn md5(&self) -> Option<&str> {
+        find_hash(&self.hashes, Hash::MD5)
+    }
+}
+
+/// `gameVersionTypeId` CurseForge uses for mod loader entries ("Forge",
+/// "Fabric"...) in `sortableGameVersions`.
+pub const MODLOADER_GAME_VERSION_TYPE_ID: i32 = 68441;
+
+/// `gameVersionTypeId` of the "Client" and "Server" entries.
+pub const ENVIRONMENT_GAME_VERSION_TYPE_ID: i32 = 75208;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Environment {
+    Client,
+    Server,
+}
+
+/// One entry of a file's mixed `gameVersions` list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameVersionTag {
+    Minecraft(String),
+    Loader(ModLoaderType),
+    Environment(Environment),
+    Java(u32),
+    Other(String),
+}
+
+impl GameVersionTag {
+    /// Classifies a bare `gameVersions` name.
+    pub fn parse(name: &str) -> Self {
+        let name = name.trim();
+        if let Some(loader) = ModLoaderType::from_name(name) {
+            return GameVersionTag::Loader(loader);
+        }
+        if let Some(environment) = Environment::from_name(name) {
+            return GameVersionTag::Environment(environment);
+        }
+        if let Some(java) = parse_java(name) {
+            return GameVersionTag::Java(java);
+        }
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            return GameVersionTag::Minecraft(name.to_string());
+        }
+        GameVersionTag::Other(name.to_string())
+    }
+
+    /// Classifies a `sortableGameVersions` entry by its
+    /// `gameVersionTypeId`. Minecraft versions are spread over one type per
+    /// major version and are the only entries with a `gameVersion`.
+    pub fn from_sortable(sortable: &FileSortableGameVersions) -> Option<Self> {
+        let name = sortable
+            .game_version_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())?;
+        let other = || GameVersionTag::Other(name.to_string());
+        let tag = match sortable.game_version_type_id {
+            Some(MODLOADER_GAME_VERSION_TYPE_ID) => {
+                ModLoaderType::from_name(name).map_or_else(other, GameVersionTag::Loader)
+            }
+            Some(ENVIRONMENT_GAME_VERSION_TYPE_ID) => {
+                Environment::from_name(name).map_or_else(other, GameVersionTag::Environment)
+            }
+            Some(_)
+                if sortable
+                    .game_version
+                    .as_deref()
+                    .is_some_and(|v| !v.is_empty()) =>
+            {
+                GameVersionTag::Minecraft(name.to_string())
+            }
+            Some(_) => parse_java(name).map_or_else(other, GameVersionTag::Java),
+            None => GameVersionTag::parse(name),
+        };
+        Some(tag)
+    }
+}
+
+impl Environment {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "client" => Some(Environment::Client),
+            "server" => Some(Environment::Server),
+            _ => None,
+        }
+    }
+}
+
+/// "Java 17", or "Java 1.8" for Java 8.
+fn parse_java(name: &str) -> Option<u32> {
+    let java = name
+        .strip_prefix("Java")
+        .or_else(|| name.strip_prefix("java"))?
+        .trim();
+    java.strip_prefix("1.").unwrap_or(java).parse().ok()
+}
+
+/// A file's `gameVersions` split by kind, in their original order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameVersionTags {
+    pub minecraft: Vec<String>,
+    pub loaders: Vec<ModLoaderType>,
+    pub environments: Vec<Environment>,
+    pub java: Vec<u32>,
+    pub other: Vec<String>,
+}
+
+impl GameVersionTags {
+    /// Entries of `sortable` are classified by `gameVersionTypeId`; names
+    /// only found in `game_versions` are classified by name.
+    pub fn new(game_versions: &[String], sortable: &[FileSortableGameVersions]) -> Self {
+        let mut tags = GameVersionTags::default();
+        for tag in sortable.iter().filter_map(GameVersionTag::from_sortable) {
+            tags.push(tag);
+        }
+        let sorted: Vec<&str> = sortable
+            .iter()
+            .filter_map(|s| s.game_version_name.as_deref())
+            .map(str::trim)
+            .collect();
+        for name in game_versions.iter().map(|n| n.trim()) {
+            if !sorted.contains(&name) {
+                tags.push(GameVersionTag::parse(name));
+            }
+        }
+        tags
+    }
+
+    fn push(&mut self, tag: GameVersionTag) {
+        fn add<T: PartialEq>(list: &mut Vec<T>, value: T) {
+            if !list.contains(&value) {
+                list.push(value);
+            }
+        }
+        match tag {
+            GameVersionTag::Minecraft(v) => add(&mut self.minecraft, v),
+            GameVersionTag::Loader(l) => add(&mut self.loaders, l),
+            GameVersionTag::Environment(e) => add(&mut self.environments, e),
+            GameVersionTag::Java(j) => add(&mut self.java, j),
+            GameVersionTag::Other(o) => add(&mut self.other, o),
+        }
+    }
+}
+
+impl File {
+    pub fn game_version_tags(&self) -> GameVersionTags {
+        GameVersionTags::new(
+            self.game_versions.as_deref().unwrap_or_default(),
+            self.sortable_game_versions.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
+impl FileInfo {
+    pub fn game_version_tags(&self) -> GameVersionTags {
+        GameVersionTags::new(
+            self.game_versions.as_deref().unwrap_or_default(),
+            self.sortable_game_versions.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
+/// The category hierarchy of one game, rebuilt from stored `Category`
+/// documents. Classes (and categories whose parent is themselves or is
+/// not stored) are roots; siblings are ordered by `display_index`, then
+/// name.
+#[derive(Debug, Clone, Default)]
+pub struct CategoryTree {
+    categories: Vec<Category>,
+    by_id: HashMap<i32, usize>,
+    parents: HashMap<i32, i32>,
+    children: HashMap<i32, Vec<i32>>,
+    roots: Vec<i32>,
+}
+
+/// A mod's categories resolved against a `CategoryTree`.
+#[derive(Debug, Clone, Default)]
+pub struct ModCategories<'a> {
+    pub primary: Option<&'a Category>,
+    pub categories: Vec<&'a Category>,
+    /// Ids referenced by the mod that are not in the tree.
+    pub unknown: Vec<i32>,
+}
+
+impl CategoryTree {
+    pub fn new(categories: Vec<Category>) -> Self {
+        let mut tree = CategoryTree {
+            by_id: categories
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (c.id, i))
+                .collect(),
+            categories,
+            ..Default::default()
+        };
+
+        for c in &tree.categories {
+            let parent = c
+                .parent_category_id
+                .or(c.class_id)
+                .filter(|p| *p != c.id && c.is_class != Some(true))
+                .filter(|p| tree.by_id.contains_key(p));
+            match parent {
+                Some(p) => {
+                    tree.parents.insert(c.id, p);
+                    tree.children.entry(p).or_default().push(c.id);
+                }
+                None => tree.roots.push(c.id),
+            }
+        }
+
+        let key = |id: &i32| {
+            let c = &tree.categories[tree.by_id[id]];
+            (c.display_index, c.name.clone(), c.id)
+        };
+        let mut roots = tree.roots.clone();
+        roots.sort_by_key(key);
+        let mut children = tree.children.clone();
+        for list in children.values_mut() {
+            list.sort_by_key(key);
+        }
+        tree.roots = roots;
+        tree.children = children;
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.categories.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+
+    pub fn get(&self, id: i32) -> Option<&Category> {
+        self.by_id.get(&id).map(|i| &self.categories[*i])
+    }
+
+    /// Slugs are only unique within a class ("fabric" exists under several),
+    /// so pass `class_id` to disambiguate; without it the first match in
+    /// tree order wins.
+    pub fn by_slug(&self, class_id: Option<i32>, slug: &str) -> Option<&Category> {
+        self.walk().into_iter().find(|c| {
+            c.slug.as_deref() == Some(slug)
+                && class_id.is_none_or(|class| c.id == class || c.class_id == Some(class))
+        })
+    }
+
+    pub fn roots(&self) -> Vec<&Category> {
+        self.roots.iter().filter_map(|id| self.get(*id)).collect()
+    }
+
+    pub fn parent(&self, id: i32) -> Option<&Category> {
+        self.parents.get(&id).and_then(|p| self.get(*p))
+    }
+
+    pub fn children(&self, id: i32) -> Vec<&Category> {
+        self.children
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter_map(|c| self.get(*c))
+            .collect()
+    }
+
+    /// Root-first ancestry ending with the category itself; empty when the
+    /// id is unknown.
+    pub fn path(&self, id: i32) -> Vec<&Category> {
+        let mut path = Vec::new();
+        let mut current = self.get(id);
+        while let Some(c) = current {
+            // Guards against parent cycles in bad data.
+            if path.iter().any(|p: &&Category| p.id == c.id) {
+                break;
+            }
+            path.push(c);
+            current = self.parent(c.id);
+        }
+        path.reverse();
+        path
+    }
+
+    /// All categories depth-first in display order.
+    pub fn walk(&self) -> Vec<&Category> {
+        fn visit<'a>(tree: &'a CategoryTree, id: i32, out: &mut Vec<&'a Category>) {
+            if let Some(c) = tree.get(id) {
+                out.push(c);
+            }
+            for child in tree.children.get(&id).into_iter().flatten() {
+                visit(tree, *child, out);
+            }
+        }
+        let mut out = Vec::with_capacity(self.categories.len());
+        for root in &self.roots {
+            visit(self, *root, &mut out);
+        }
+        out
+    }
+
+    pub fn resolve(&self, categories: &[CategoryInfo]) -> Vec<&Category> {
+        categories
+            .iter()
+            .filter_map(|c| c.id.and_then(|id| self.get(id)))
+            .collect()
+    }
+
+    pub fn mod_categories(&self, m: &Mod) -> ModCategories<'_> {
+        let mut resolved = ModCategories {
+            primary: m.primary_category_id.and_then(|id| self.get(id)),
+            ..Default::default()
+        };
+        let ids = m.categories.iter().flatten().filter_map(|c| c.id);
+        for id in ids.chain(m.primary_category_id) {
+            match self.get(id) {
+                Some(c) if !resolved.categories.iter().any(|r| r.id == id) => {
+                    resolved.categories.push(c)
+                }
+                None if !resolved.unknown.contains(&id) => resolved.unknown.push(id),
+                _ => {}
+            }
+        }
+        resolved
+    }
+}
+
+impl FromIterator<Category> for CategoryTree {
+    fn from_iter<I: IntoIterator<Item = Category>>(iter: I) -> Self {
+        CategoryTree::new(iter.into_iter().collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -864,4 +1539,170 @@ mod tests {
         assert_eq!(c.id, 6946);
         assert_eq!(c.name, "Mod Support");
     }
+
+    #[test]
+    fn test_curseforge_file_info_conversion() {
+        let json = r###"
+        {
+            "_id": 594678,
+            "slug": "project-594678",
+            "latestFiles": [
+                {
+                    "id": 3872689,
+                    "gameId": 432,
+                    "modId": 594678,
+                    "displayName": "hats-and-cosmetics-1.2.1-1.19",
+                    "fileName": "hats-and-cosmetics-1.2.1-1.19.jar",
+                    "releaseType": 2,
+                    "gameVersions": ["Forge", "1.19"],
+                    "fileFingerprint": 2194437269
+                },
+                {
+                    "id": 3913840,
+                    "gameId": 432,
+                    "modId": 0,
+                    "fileName": "hats-and-cosmetics-1.2.2-1.19.1.jar"
+                }
+            ],
+            "sync_at": {
+                "$date": "2025-06-17T10:46:29.504Z"
+            }
+        }
+        "###;
+
+        let m: Mod = serde_json::from_str(json).expect("deserialize mod from json");
+        let files = m.to_files();
+        assert_eq!(files.len(), 2);
+        assert!(
+            files
+                .iter()
+                .all(|f| f.mod_id == 594678 && f.sync_at == m.sync_at)
+        );
+        assert_eq!(files[0].file_fingerprint, Some(2194437269));
+
+        let info = files[0].info();
+        assert_eq!(info.id, 3872689);
+        assert_eq!(info.game_versions, files[0].game_versions);
+        let back = File::from_info(info, m.sync_at);
+        assert_eq!(back.display_name, files[0].display_name);
+    }
+
+    #[test]
+    fn test_curseforge_game_version_tags() {
+        let json = r###"
+        {
+            "id": 4012345,
+            "gameId": 432,
+            "modId": 594678,
+            "gameVersions": ["Forge", "NeoForge", "1.20.1", "Client", "Server", "Java 17", "1.20-Snapshot"],
+            "sortableGameVersions": [
+                {
+                    "gameVersionName": "NeoForge",
+                    "gameVersionPadded": "0",
+                    "gameVersion": "",
+                    "gameVersionTypeId": 68441
+                },
+                {
+                    "gameVersionName": "Rift",
+                    "gameVersionPadded": "0",
+                    "gameVersion": "",
+                    "gameVersionTypeId": 68441
+                },
+                {
+                    "gameVersionName": "Client",
+                    "gameVersionPadded": "0",
+                    "gameVersion": "",
+                    "gameVersionTypeId": 75208
+                },
+                {
+                    "gameVersionName": "Java 17",
+                    "gameVersionPadded": "0",
+                    "gameVersion": "",
+                    "gameVersionTypeId": 8
+                },
+                {
+                    "gameVersionName": "1.20.1",
+                    "gameVersionPadded": "0000000001.0000000020.0000000001",
+                    "gameVersion": "1.20.1",
+                    "gameVersionTypeId": 75125
+                }
+            ],
+            "sync_at": {
+                "$date": "2025-06-17T10:46:29.504Z"
+            }
+        }
+        "###;
+
+        let file: File = serde_json::from_str(json).expect("deserialize file from json");
+        let tags = file.game_version_tags();
+        assert_eq!(tags.minecraft, ["1.20.1", "1.20-Snapshot"]);
+        assert_eq!(
+            tags.loaders,
+            [ModLoaderType::NeoForge, ModLoaderType::Forge]
+        );
+        assert_eq!(
+            tags.environments,
+            [Environment::Client, Environment::Server]
+        );
+        assert_eq!(tags.java, [17]);
+        assert_eq!(tags.other, ["Rift"]);
+
+        assert_eq!(file.info().game_version_tags(), tags);
+        assert_eq!(GameVersionTag::parse("Java 1.8"), GameVersionTag::Java(8));
+        assert_eq!(
+            GameVersionTag::parse("fabric"),
+            GameVersionTag::Loader(ModLoaderType::Fabric)
+        );
+    }
+
+    #[test]
+    fn test_curseforge_category_tree() {
+        let json = r###"
+        [
+            { "_id": 6, "gameId": 432, "name": "Mods", "slug": "mc-mods", "dateModified": { "$date": "2014-05-08T17:44:39.057Z" }, "isClass": true, "classId": null, "parentCategoryId": 6, "displayIndex": 1, "sync_at": { "$date": "2025-06-18T16:00:01.248Z" } },
+            { "_id": 6945, "gameId": 432, "name": "Data Packs", "slug": "data-packs", "dateModified": { "$date": "2024-02-06T12:41:02.673Z" }, "isClass": true, "parentCategoryId": null, "displayIndex": 0, "sync_at": { "$date": "2025-06-18T16:00:01.248Z" } },
+            { "_id": 6946, "gameId": 432, "name": "Mod Support", "slug": "mod-support", "dateModified": { "$date": "2024-02-06T12:41:02.673Z" }, "isClass": null, "classId": 6945, "parentCategoryId": 6945, "displayIndex": 0, "sync_at": { "$date": "2025-06-18T16:00:01.248Z" } },
+            { "_id": 434, "gameId": 432, "name": "Armor, Tools, and Weapons", "slug": "armor-weapons-tools", "dateModified": { "$date": "2014-05-08T17:44:39.057Z" }, "isClass": false, "classId": 6, "parentCategoryId": 6, "displayIndex": 0, "sync_at": { "$date": "2025-06-18T16:00:01.248Z" } },
+            { "_id": 424, "gameId": 432, "name": "Cosmetic", "slug": "cosmetic", "dateModified": { "$date": "2014-05-08T17:42:35.597Z" }, "isClass": false, "classId": 6, "parentCategoryId": 6, "displayIndex": 0, "sync_at": { "$date": "2025-06-18T16:00:01.248Z" } },
+            { "_id": 4485, "gameId": 432, "name": "Blood Magic", "slug": "blood-magic", "dateModified": { "$date": "2014-05-08T17:42:35.597Z" }, "isClass": false, "classId": 6, "parentCategoryId": 6946, "displayIndex": 0, "sync_at": { "$date": "2025-06-18T16:00:01.248Z" } }
+        ]
+        "###;
+        let categories: Vec<Category> =
+            serde_json::from_str(json).expect("deserialize categories from json");
+        let tree: CategoryTree = categories.into_iter().collect();
+
+        let roots: Vec<i32> = tree.roots().iter().map(|c| c.id).collect();
+        assert_eq!(roots, [6945, 6]);
+        let children: Vec<&str> = tree.children(6).iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(children, ["Armor, Tools, and Weapons", "Cosmetic"]);
+        let path: Vec<i32> = tree.path(4485).iter().map(|c| c.id).collect();
+        assert_eq!(path, [6945, 6946, 4485]);
+        assert!(tree.parent(6).is_none());
+        assert_eq!(tree.walk().len(), tree.len());
+        assert_eq!(tree.by_slug(Some(6), "cosmetic").map(|c| c.id), Some(424));
+        assert!(tree.by_slug(Some(6945), "cosmetic").is_none());
+
+        let m: Mod = serde_json::from_str(
+            r###"
+            {
+                "_id": 594678,
+                "slug": "hats-and-cosmetics",
+                "primaryCategoryId": 424,
+                "categories": [{ "id": 434 }, { "id": 424 }, { "id": 9999 }],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            }
+            "###,
+        )
+        .expect("deserialize mod from json");
+        let resolved = tree.mod_categories(&m);
+        assert_eq!(resolved.primary.map(|c| c.id), Some(424));
+        let ids: Vec<i32> = resolved.categories.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [434, 424]);
+        assert_eq!(resolved.unknown, [9999]);
+        assert_eq!(
+            tree.resolve(m.categories.as_deref().unwrap_or_default())
+                .len(),
+            2
+        );
+    }
 }
@@ -0,0 +1,864 @@
+use chrono::{DateTime, Utc};
+
+use crate::translate::{
+    CurseForgeTranslation, Locale, ModrinthTranslation, Translation, TranslationField,
+    TranslationSubject,
+};
+
+/// A translation record that can round-trip through PO and XLIFF files.
+pub trait Translatable {
+    /// Identifies the record in exported files (`msgctxt` / unit id).
+    fn context(&self) -> String;
+    fn original(&self) -> Option<&str>;
+    fn translated(&self) -> Option<&str>;
+    fn need_to_update(&self) -> bool;
+    fn apply(&mut self, translated: String, at: DateTime<Utc>);
+
+    /// Pending items are exported for translators.
+    fn is_pending(&self) -> bool {
+        self.need_to_update() || self.translated().is_none()
+    }
+}
+
+impl Translatable for ModrinthTranslation {
+    fn context(&self) -> String {
+        self.project_id.clone()
+    }
+
+    fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    fn translated(&self) -> Option<&str> {
+        self.translated.as_deref()
+    }
+
+    fn need_to_update(&self) -> bool {
+        self.need_to_update
+    }
+
+    fn apply(&mut self, translated: String, at: DateTime<Utc>) {
+        self.translated = Some(translated);
+        self.need_to_update = false;
+        self.translated_at = Some(at);
+    }
+}
+
+impl Translatable for CurseForgeTranslation {
+    fn context(&self) -> String {
+        self.mod_id.to_string()
+    }
+
+    fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    fn translated(&self) -> Option<&str> {
+        self.translated.as_deref()
+    }
+
+    fn need_to_update(&self) -> bool {
+        self.need_to_update
+    }
+
+    fn apply(&mut self, translated: String, at: DateTime<Utc>) {
+        self.translated = Some(translated);
+        self.need_to_update = false;
+        self.translated_at = Some(at);
+    }
+}
+
+impl Translatable for Translation {
+    /// `subject/id/field`, e.g. `modrinth_project/Wnxd13zP/summary`. The
+    /// locale is left out: an exported file holds a single target language.
+    fn context(&self) -> String {
+        let (kind, id) = match &self.key.subject {
+            TranslationSubject::ModrinthProject(id) => ("modrinth_project", id.clone()),
+            TranslationSubject::ModrinthVersion(id) => ("modrinth_version", id.clone()),
+            TranslationSubject::ModrinthCategory(id) => ("modrinth_category", id.clone()),
+            TranslationSubject::CurseForgeMod(id) => ("curseforge_mod", id.to_string()),
+            TranslationSubject::CurseForgeFile(id) => ("curseforge_file", id.to_string()),
+            TranslationSubject::CurseForgeCategory(id) => ("curseforge_category", id.to_string()),
+        };
+        let field = match self.key.field {
+            TranslationField::Title => "title",
+            TranslationField::Summary => "summary",
+            TranslationField::Body => "body",
+            TranslationField::Changelog => "changelog",
+            TranslationField::Name => "name",
+        };
+        format!("{kind}/{id}/{field}")
+    }
+
+    fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    fn translated(&self) -> Option<&str> {
+        self.translated.as_deref()
+    }
+
+    fn need_to_update(&self) -> bool {
+        self.need_to_update
+    }
+
+    fn apply(&mut self, translated: String, at: DateTime<Utc>) {
+        self.translated = Some(translated);
+        self.need_to_update = false;
+        self.translated_at = Some(at);
+    }
+}
+
+/// One entry read back from a PO or XLIFF file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unit {
+    pub context: String,
+    pub source: String,
+    pub target: Option<String>,
+    /// False for fuzzy PO entries and XLIFF targets still marked as needing
+    /// translation or review.
+    pub accepted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportConflict {
+    pub context: String,
+    /// The source text the translator worked from.
+    pub exported: String,
+    /// The record's `original` at import time.
+    pub current: Option<String>,
+    pub translated: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportReport<T> {
+    pub updated: Vec<T>,
+    pub conflicts: Vec<ImportConflict>,
+    /// Contexts with no target, or whose target was not accepted.
+    pub skipped: Vec<String>,
+    /// Contexts with no matching record.
+    pub unknown: Vec<String>,
+}
+
+pub fn pending<T: Translatable>(items: &[T]) -> impl Iterator<Item = &T> {
+    items
+        .iter()
+        .filter(|t| t.is_pending() && t.original().is_some())
+}
+
+/// Applies translated units to the matching records. A unit whose source
+/// no longer matches the record's `original` is reported as a conflict and
+/// left alone.
+pub fn import<T: Translatable + Clone>(
+    units: &[Unit],
+    current: &[T],
+    at: DateTime<Utc>,
+) -> ImportReport<T> {
+    let mut report = ImportReport {
+        updated: Vec::new(),
+        conflicts: Vec::new(),
+        skipped: Vec::new(),
+        unknown: Vec::new(),
+    };
+    for unit in units {
+        let Some(record) = current.iter().find(|t| t.context() == unit.context) else {
+            report.unknown.push(unit.context.clone());
+            continue;
+        };
+        let target = unit.target.as_deref().filter(|t| !t.trim().is_empty());
+        let Some(target) = target.filter(|_| unit.accepted) else {
+            report.skipped.push(unit.context.clone());
+            continue;
+        };
+        if record.original() != Some(unit.source.as_str()) {
+            report.conflicts.push(ImportConflict {
+                context: unit.context.clone(),
+                exported: unit.source.clone(),
+                current: record.original().map(str::to_string),
+                translated: target.to_string(),
+            });
+            continue;
+        }
+        let mut record = record.clone();
+        record.apply(target.to_string(), at);
+        report.updated.push(record);
+    }
+    report
+}
+
+fn po_quote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn po_unquote(value: &str, line: usize) -> Result<String, ParseError> {
+    let error = |message: &str| ParseError {
+        line,
+        message: message.to_string(),
+    };
+    let inner = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .ok_or_else(|| error("expected a quoted string"))?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            _ => return Err(error("invalid escape sequence")),
+        }
+    }
+    Ok(out)
+}
+
+/// Writes pending items as a gettext PO file. Items that already have a
+/// (stale) translation keep it as a fuzzy `msgstr`.
+pub fn export_po<T: Translatable>(items: &[T], language: &Locale) -> String {
+    let mut out = String::new();
+    out.push_str("msgid \"\"\nmsgstr \"\"\n");
+    out.push_str(&format!(
+        "\"Language: {}\\n\"\n",
+        language.as_str().replace('-', "_")
+    ));
+    out.push_str("\"MIME-Version: 1.0\\n\"\n");
+    out.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    out.push_str("\"Content-Transfer-Encoding: 8bit\\n\"\n");
+    for item in pending(items) {
+        out.push('\n');
+        if item.translated().is_some() {
+            out.push_str("#, fuzzy\n");
+        }
+        out.push_str(&format!("msgctxt {}\n", po_quote(&item.context())));
+        out.push_str(&format!(
+            "msgid {}\n",
+            po_quote(item.original().unwrap_or(""))
+        ));
+        out.push_str(&format!(
+            "msgstr {}\n",
+            po_quote(item.translated().unwrap_or(""))
+        ));
+    }
+    out
+}
+
+pub fn parse_po(input: &str) -> Result<Vec<Unit>, ParseError> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        None,
+        Context,
+        Id,
+        Str,
+        /// `msgid_plural` and `msgstr[n]` strings, which units do not carry.
+        Plural,
+    }
+
+    #[derive(Default)]
+    struct Entry {
+        context: Option<String>,
+        id: Option<String>,
+        target: Option<String>,
+        fuzzy: bool,
+    }
+
+    fn finish(entry: Entry, units: &mut Vec<Unit>) {
+        let (Some(context), Some(source)) = (entry.context, entry.id) else {
+            return;
+        };
+        units.push(Unit {
+            context,
+            source,
+            target: entry.target.filter(|t| !t.is_empty()),
+            accepted: !entry.fuzzy,
+        });
+    }
+
+    let mut units = Vec::new();
+    let mut entry = Entry::default();
+    let mut field = Field::None;
+    for (i, line) in input.lines().enumerate() {
+        let number = i + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            finish(std::mem::take(&mut entry), &mut units);
+            field = Field::None;
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            if field != Field::None {
+                finish(std::mem::take(&mut entry), &mut units);
+                field = Field::None;
+            }
+            entry.fuzzy |= flags.split(',').any(|f| f.trim() == "fuzzy");
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let (keyword, rest) = match line.split_once(char::is_whitespace) {
+            Some((keyword, rest)) if !line.starts_with('"') => (keyword, rest.trim()),
+            _ => ("", line),
+        };
+        let value = po_unquote(rest, number)?;
+        let next = match keyword {
+            "" => field,
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Id,
+            "msgstr" | "msgstr[0]" => Field::Str,
+            "msgid_plural" => Field::Plural,
+            other if is_plural_msgstr(other) => Field::Plural,
+            other => {
+                return Err(ParseError {
+                    line: number,
+                    message: format!("unknown keyword {other}"),
+                });
+            }
+        };
+        if keyword == "msgctxt" && field != Field::None {
+            finish(std::mem::take(&mut entry), &mut units);
+        }
+        let slot = match next {
+            Field::Context => &mut entry.context,
+            Field::Id => &mut entry.id,
+            Field::Str => &mut entry.target,
+            Field::Plural => {
+                field = next;
+                continue;
+            }
+            Field::None => {
+                return Err(ParseError {
+                    line: number,
+                    message: "string outside of an entry".to_string(),
+                });
+            }
+        };
+        if keyword.is_empty() {
+            slot.get_or_insert_with(String::new).push_str(&value);
+        } else {
+            *slot = Some(value);
+        }
+        field = next;
+    }
+    finish(entry, &mut units);
+    Ok(units)
+}
+
+fn is_plural_msgstr(keyword: &str) -> bool {
+    keyword
+        .strip_prefix("msgstr[")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .and_then(|n| n.parse::<u32>().ok())
+        .is_some_and(|n| n > 0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XliffVersion {
+    V1_2,
+    V2_0,
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn xml_unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Writes pending items as XLIFF. Stale translations are included as
+/// targets awaiting review.
+pub fn export_xliff<T: Translatable>(
+    items: &[T],
+    file_id: &str,
+    source: &Locale,
+    target: &Locale,
+    version: XliffVersion,
+) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    match version {
+        XliffVersion::V1_2 => {
+            out.push_str(
+                "<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n",
+            );
+            out.push_str(&format!(
+                "  <file original=\"{}\" source-language=\"{}\" target-language=\"{}\" datatype=\"plaintext\">\n    <body>\n",
+                xml_escape(file_id),
+                source,
+                target
+            ));
+            for item in pending(items) {
+                let id = xml_escape(&item.context());
+                out.push_str(&format!(
+                    "      <trans-unit id=\"{id}\" resname=\"{id}\">\n        <source>{}</source>\n",
+                    xml_escape(item.original().unwrap_or(""))
+                ));
+                match item.translated() {
+                    Some(t) => out.push_str(&format!(
+                        "        <target state=\"needs-review-translation\">{}</target>\n",
+                        xml_escape(t)
+                    )),
+                    None => out.push_str("        <target state=\"needs-translation\"/>\n"),
+                }
+                out.push_str("      </trans-unit>\n");
+            }
+            out.push_str("    </body>\n  </file>\n</xliff>\n");
+        }
+        XliffVersion::V2_0 => {
+            out.push_str(&format!(
+                "<xliff xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" version=\"2.0\" srcLang=\"{source}\" trgLang=\"{target}\">\n  <file id=\"{}\">\n",
+                xml_escape(file_id)
+            ));
+            for item in pending(items) {
+                out.push_str(&format!(
+                    "    <unit id=\"{}\">\n      <segment state=\"initial\">\n        <source>{}</source>\n",
+                    xml_escape(&item.context()),
+                    xml_escape(item.original().unwrap_or(""))
+                ));
+                if let Some(t) = item.translated() {
+                    out.push_str(&format!("        <target>{}</target>\n", xml_escape(t)));
+                }
+                out.push_str("      </segment>\n    </unit>\n");
+            }
+            out.push_str("  </file>\n</xliff>\n");
+        }
+    }
+    out
+}
+
+/// Looks up `name` among the `name="value"` pairs of a tag body, stepping
+/// over quoted values so their contents never match.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag
+        .trim_start()
+        .trim_start_matches(|c: char| !c.is_whitespace());
+    loop {
+        rest = rest.trim_start();
+        let end = rest.find(|c: char| c == '=' || c.is_whitespace())?;
+        let key = &rest[..end];
+        let after = rest[end..].trim_start().strip_prefix('=')?.trim_start();
+        let quote = after.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value = &after[1..];
+        let close = value.find(quote)?;
+        if key == name {
+            return Some(xml_unescape(&value[..close]));
+        }
+        rest = &value[close + 1..];
+    }
+}
+
+/// Reads XLIFF 1.2 or 2.0. Inline markup inside `source`/`target` is
+/// dropped and only its text kept.
+pub fn parse_xliff(input: &str) -> Result<Vec<Unit>, ParseError> {
+    let line_of = |offset: usize| input[..offset].matches('\n').count() + 1;
+    let error = |offset: usize, message: &str| ParseError {
+        line: line_of(offset),
+        message: message.to_string(),
+    };
+
+    let mut units = Vec::new();
+    let mut unit_id: Option<String> = None;
+    let mut state: Option<String> = None;
+    // XLIFF 2.0 `<unit>` rather than 1.2 `<trans-unit>`.
+    let mut v2 = false;
+    let mut source: Option<String> = None;
+    let mut target: Option<String> = None;
+    // Which of source/target is being collected, and its nesting depth.
+    let mut collecting: Option<(bool, usize)> = None;
+
+    let mut pos = 0;
+    while pos < input.len() {
+        let Some(start) = input[pos..].find('<').map(|i| pos + i) else {
+            break;
+        };
+        if let Some((is_target, _)) = collecting {
+            let text = xml_unescape(&input[pos..start]);
+            let slot = if is_target { &mut target } else { &mut source };
+            slot.get_or_insert_with(String::new).push_str(&text);
+        }
+        let rest = &input[start..];
+        if rest.starts_with("<!--") {
+            let end = rest
+                .find("-->")
+                .ok_or_else(|| error(start, "unterminated comment"))?;
+            pos = start + end + 3;
+            continue;
+        }
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata
+                .find("]]>")
+                .ok_or_else(|| error(start, "unterminated CDATA section"))?;
+            if let Some((is_target, _)) = collecting {
+                let slot = if is_target { &mut target } else { &mut source };
+                slot.get_or_insert_with(String::new).push_str(&cdata[..end]);
+            }
+            pos = start + 9 + end + 3;
+            continue;
+        }
+        let end = rest
+            .find('>')
+            .ok_or_else(|| error(start, "unterminated tag"))?;
+        let tag = &rest[1..end];
+        pos = start + end + 1;
+        if tag.starts_with('?') || tag.starts_with('!') {
+            continue;
+        }
+        let closing = tag.starts_with('/');
+        let self_closing = tag.ends_with('/');
+        let body = tag.trim_start_matches('/').trim_end_matches('/');
+        let name = body.split_whitespace().next().unwrap_or("");
+        let name = name.rsplit(':').next().unwrap_or(name);
+
+        if let Some((is_target, depth)) = collecting {
+            let own = if is_target { "target" } else { "source" };
+            if closing && depth == 0 && name == own {
+                collecting = None;
+            } else if closing {
+                collecting = Some((is_target, depth.saturating_sub(1)));
+            } else if !self_closing {
+                collecting = Some((is_target, depth + 1));
+            }
+            continue;
+        }
+
+        match (name, closing) {
+            ("trans-unit" | "unit", false) => {
+                unit_id = attribute(body, "id");
+                v2 = name == "unit";
+                if unit_id.is_none() {
+                    return Err(error(start, "unit without an id"));
+                }
+                state = None;
+                source = None;
+                target = None;
+            }
+            ("segment", false) => {
+                state = attribute(body, "state").or(state);
+            }
+            ("source", false) if unit_id.is_some() => {
+                source = Some(String::new());
+                if !self_closing {
+                    collecting = Some((false, 0));
+                }
+            }
+            ("target", false) if unit_id.is_some() => {
+                state = attribute(body, "state").or(state);
+                target = Some(String::new());
+                if !self_closing {
+                    collecting = Some((true, 0));
+                }
+            }
+            ("trans-unit" | "unit", true) => {
+                let context = unit_id
+                    .take()
+                    .ok_or_else(|| error(start, "unexpected end of unit"))?;
+                let source = source
+                    .take()
+                    .ok_or_else(|| error(start, "unit without a source"))?;
+                // 2.0 segments default to `initial`, so only an explicit
+                // translated state counts; 1.2 targets without a state do.
+                let accepted = if v2 {
+                    matches!(state.as_deref(), Some("translated" | "reviewed" | "final"))
+                } else {
+                    !matches!(
+                        state.as_deref(),
+                        Some(
+                            "new"
+                                | "needs-translation"
+                                | "needs-adaptation"
+                                | "needs-l10n"
+                                | "needs-review-translation"
+                                | "needs-review-adaptation"
+                                | "needs-review-l10n"
+                        )
+                    )
+                };
+                units.push(Unit {
+                    context,
+                    source,
+                    target: target.take().filter(|t| !t.is_empty()),
+                    accepted,
+                });
+            }
+            _ => {}
+        }
+    }
+    if collecting.is_some() || unit_id.is_some() {
+        return Err(error(input.len(), "unexpected end of document"));
+    }
+    Ok(units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn translations() -> Vec<ModrinthTranslation> {
+        let json = r###"
+        [
+            {
+                "_id": "Wnxd13zP",
+                "original": "Clumps XP orbs together to reduce lag",
+                "translated": "将经验球聚集在一起以减少游戏卡顿。",
+                "translated_at": { "$date": "2025-02-02T08:53:28.684Z" },
+                "need_to_update": true
+            },
+            {
+                "_id": "P7dR8mSH",
+                "original": "Lightweight & modular API\n\"for\" Fabric",
+                "translated": null,
+                "translated_at": null,
+                "need_to_update": false
+            },
+            {
+                "_id": "Ua7DFN59",
+                "original": "Library mod for YUNG's mods.",
+                "translated": "YUNG 模组的前置库。",
+                "translated_at": { "$date": "2025-02-02T08:53:28.684Z" },
+                "need_to_update": false
+            }
+        ]
+        "###;
+        serde_json::from_str(json).expect("deserialize translations from json")
+    }
+
+    #[test]
+    fn test_po_round_trip() {
+        let items = translations();
+        let po = export_po(&items, &Locale::zh_cn());
+        assert!(po.contains("\"Language: zh_CN\\n\""));
+        assert!(po.contains("#, fuzzy\nmsgctxt \"Wnxd13zP\""));
+        assert!(po.contains("msgid \"Lightweight & modular API\\n\\\"for\\\" Fabric\""));
+        assert!(!po.contains("Ua7DFN59"));
+
+        let units = parse_po(&po).expect("parse exported po");
+        assert_eq!(units.len(), 2);
+        assert!(!units[0].accepted);
+        assert_eq!(units[1].source, "Lightweight & modular API\n\"for\" Fabric");
+        assert_eq!(units[1].target, None);
+
+        let msgid = "msgid \"Lightweight & modular API\\n\\\"for\\\" Fabric\"\n";
+        let edited = po.replace("#, fuzzy\n", "").replace(
+            &format!("{msgid}msgstr \"\"\n"),
+            &format!("{msgid}msgstr \"\"\n\"适用于 Fabric 的\"\n\"轻量模块化 API\"\n"),
+        );
+        let units = parse_po(&edited).expect("parse edited po");
+        assert_eq!(
+            units[1].target.as_deref(),
+            Some("适用于 Fabric 的轻量模块化 API")
+        );
+
+        let mut current = items.clone();
+        current[0].original = Some("Clumps XP orbs together".to_string());
+        let at = Utc::now();
+        let report = import(&units, &current, at);
+        assert_eq!(report.updated.len(), 1);
+        assert_eq!(report.updated[0].project_id, "P7dR8mSH");
+        assert_eq!(report.updated[0].translated_at, Some(at));
+        assert!(!report.updated[0].need_to_update);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].context, "Wnxd13zP");
+        assert!(report.unknown.is_empty());
+    }
+
+    #[test]
+    fn test_po_plural_entries() {
+        let po = r###"msgctxt "Wnxd13zP"
+msgid "x"
+msgid_plural "xs"
+"and more xs"
+msgstr[0] "y"
+msgstr[1] "ys"
+"more"
+msgstr[3] "yyys"
+
+msgctxt "P7dR8mSH"
+msgid "Clumps XP orbs"
+msgstr "合并经验球"
+"###;
+        let units = parse_po(po).expect("parse plural po");
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].source, "x");
+        assert_eq!(units[0].target.as_deref(), Some("y"));
+        assert_eq!(units[1].target.as_deref(), Some("合并经验球"));
+
+        assert!(parse_po("msgstr[x] \"y\"\n").is_err());
+    }
+
+    #[test]
+    fn test_xliff_round_trip() {
+        let items = translations();
+        let source = Locale::parse("en").unwrap();
+        for version in [XliffVersion::V1_2, XliffVersion::V2_0] {
+            let xliff = export_xliff(&items, "modrinth", &source, &Locale::zh_cn(), version);
+            assert!(xliff.contains("Lightweight &amp; modular API\n&quot;for&quot; Fabric"));
+
+            let units = parse_xliff(&xliff).expect("parse exported xliff");
+            assert_eq!(units.len(), 2);
+            assert_eq!(units[0].context, "Wnxd13zP");
+            assert_eq!(units[1].source, "Lightweight & modular API\n\"for\" Fabric");
+            assert_eq!(units[1].target, None);
+            assert_eq!(
+                units[0].target.as_deref(),
+                Some("将经验球聚集在一起以减少游戏卡顿。")
+            );
+        }
+
+        let edited = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="zh-CN">
+  <file id="curseforge">
+    <!-- reviewed -->
+    <unit id="238222">
+      <segment state="translated">
+        <source>View Items and Recipes</source>
+        <target>查看<pc id="1">物品</pc>和配方</target>
+      </segment>
+    </unit>
+  </file>
+</xliff>
+"###;
+        let units = parse_xliff(edited).expect("parse edited xliff");
+        assert_eq!(units[0].target.as_deref(), Some("查看物品和配方"));
+        assert!(units[0].accepted);
+
+        let current: Vec<CurseForgeTranslation> = serde_json::from_str(
+            r#"[{ "_id": 238222, "original": "View Items and Recipes", "translated": null, "translated_at": null, "need_to_update": false }]"#,
+        )
+        .expect("deserialize translations from json");
+        let report = import(&units, &current, Utc::now());
+        assert_eq!(
+            report.updated[0].translated.as_deref(),
+            Some("查看物品和配方")
+        );
+
+        let quoted = r###"<xliff version="1.2"><file><body>
+<trans-unit resname="x id=1" id="u1"><source>a</source><target>b</target></trans-unit>
+</body></file></xliff>"###;
+        let units = parse_xliff(quoted).expect("parse xliff with quoted attribute");
+        assert_eq!(units[0].context, "u1");
+
+        assert!(parse_xliff("<xliff><unit id=\"1\"><source>x</source>").is_err());
+    }
+
+    #[test]
+    fn test_xliff_v2_stale_round_trip() {
+        let items = translations();
+        let source = Locale::parse("en").unwrap();
+        let xliff = export_xliff(
+            &items,
+            "modrinth",
+            &source,
+            &Locale::zh_cn(),
+            XliffVersion::V2_0,
+        );
+        let units = parse_xliff(&xliff).expect("parse exported xliff");
+        assert!(units.iter().all(|u| !u.accepted));
+
+        let report = import(&units, &items, Utc::now());
+        assert!(report.updated.is_empty());
+        assert_eq!(report.skipped, ["Wnxd13zP", "P7dR8mSH"]);
+
+        let items: Vec<Translation> = translations().into_iter().map(Translation::from).collect();
+        let xliff = export_xliff(
+            &items,
+            "modrinth",
+            &source,
+            &Locale::zh_cn(),
+            XliffVersion::V2_0,
+        );
+        let edited = xliff.replacen(
+            "<segment state=\"initial\">",
+            "<segment state=\"translated\">",
+            1,
+        );
+        let units = parse_xliff(&edited).expect("parse edited xliff");
+        assert_eq!(units[0].context, "modrinth_project/Wnxd13zP/summary");
+        assert!(units[0].accepted);
+        assert!(!units[1].accepted);
+
+        let report = import(&units, &items, Utc::now());
+        assert_eq!(report.updated.len(), 1);
+        assert!(!report.updated[0].need_to_update);
+        assert_eq!(report.skipped, ["modrinth_project/P7dR8mSH/summary"]);
+    }
+}
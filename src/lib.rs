@@ -1,3 +1,11 @@
 pub mod curseforge;
+pub mod interchange;
+pub mod matching;
+pub mod minecraft;
+pub mod mirror;
+pub mod mod_version;
 pub mod modrinth;
-pub mod translate;
\ No newline at end of file
+pub mod platform;
+pub mod resolver;
+pub mod translate;
+pub mod update;
\ No newline at end of file
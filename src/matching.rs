@@ -0,0 +1,270 @@
+use crate::{curseforge, modrinth};
+
+/// Probability that two projects are the same given one piece of evidence;
+/// independent signals are combined as `1 - Π(1 - p)`.
+const SHARED_HASH_WEIGHT: f64 = 0.99;
+const SOURCE_URL_WEIGHT: f64 = 0.9;
+const SLUG_WEIGHT: f64 = 0.6;
+const NAME_WEIGHT: f64 = 0.5;
+
+/// Similarities below this are noise and are not reported as evidence.
+const MIN_SIMILARITY: f64 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchEvidence {
+    SharedFileHash { sha1: String },
+    SameSourceUrl { url: String },
+    SlugSimilarity { score: f64 },
+    NameSimilarity { score: f64 },
+}
+
+impl MatchEvidence {
+    fn weight(&self) -> f64 {
+        match self {
+            MatchEvidence::SharedFileHash { .. } => SHARED_HASH_WEIGHT,
+            MatchEvidence::SameSourceUrl { .. } => SOURCE_URL_WEIGHT,
+            MatchEvidence::SlugSimilarity { score } => SLUG_WEIGHT * score,
+            MatchEvidence::NameSimilarity { score } => NAME_WEIGHT * score,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectMatch {
+    pub modrinth_id: String,
+    pub curseforge_id: i32,
+    pub confidence: f64,
+    pub evidence: Vec<MatchEvidence>,
+}
+
+/// Scores how likely `project` and `m` are the same mod. `versions` are the
+/// Modrinth project's versions and `files` any stored CurseForge files of
+/// the mod; the mod's own `latest_files` are always considered.
+pub fn score(
+    project: &modrinth::Project,
+    versions: &[modrinth::Version],
+    m: &curseforge::Mod,
+    files: &[curseforge::File],
+) -> ProjectMatch {
+    let mut evidence = Vec::new();
+
+    let curseforge_sha1: Vec<String> = files
+        .iter()
+        .filter(|f| f.mod_id == m.id)
+        .filter_map(|f| f.sha1())
+        .chain(m.latest_files.iter().flatten().filter_map(|f| f.sha1()))
+        .map(|h| h.to_ascii_lowercase())
+        .collect();
+    let mut shared: Vec<String> = versions
+        .iter()
+        .filter(|v| v.project_id == project.id)
+        .flat_map(|v| v.files.iter())
+        .map(|f| f.hashes.sha1.to_ascii_lowercase())
+        .filter(|h| curseforge_sha1.contains(h))
+        .collect();
+    shared.sort();
+    shared.dedup();
+    evidence.extend(
+        shared
+            .into_iter()
+            .map(|sha1| MatchEvidence::SharedFileHash { sha1 }),
+    );
+
+    let curseforge_source = m.links.as_ref().and_then(|l| l.source_url.as_deref());
+    if let (Some(a), Some(b)) = (project.source_url.as_deref(), curseforge_source) {
+        let a = normalize_url(a);
+        if !a.is_empty() && a == normalize_url(b) {
+            evidence.push(MatchEvidence::SameSourceUrl { url: a });
+        }
+    }
+
+    let slug = similarity(&project.slug, &m.slug);
+    if slug >= MIN_SIMILARITY {
+        evidence.push(MatchEvidence::SlugSimilarity { score: slug });
+    }
+    if let (Some(a), Some(b)) = (project.title.as_deref(), m.name.as_deref()) {
+        let name = similarity(a, b);
+        if name >= MIN_SIMILARITY {
+            evidence.push(MatchEvidence::NameSimilarity { score: name });
+        }
+    }
+
+    // Each shared hash is strong on its own; more of them add nothing.
+    let mut seen_hash = false;
+    let mut miss = 1.0;
+    for e in &evidence {
+        if let MatchEvidence::SharedFileHash { .. } = e {
+            if seen_hash {
+                continue;
+            }
+            seen_hash = true;
+        }
+        miss *= 1.0 - e.weight();
+    }
+
+    ProjectMatch {
+        modrinth_id: project.id.clone(),
+        curseforge_id: m.id,
+        confidence: 1.0 - miss,
+        evidence,
+    }
+}
+
+/// The highest-scoring candidate at or above `min_confidence`.
+pub fn best_match(
+    project: &modrinth::Project,
+    versions: &[modrinth::Version],
+    candidates: &[curseforge::Mod],
+    files: &[curseforge::File],
+    min_confidence: f64,
+) -> Option<ProjectMatch> {
+    candidates
+        .iter()
+        .map(|m| score(project, versions, m, files))
+        .filter(|r| r.confidence >= min_confidence)
+        .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+}
+
+fn normalize_url(url: &str) -> String {
+    let url = url.trim().to_ascii_lowercase();
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(&url);
+    let url = url.strip_prefix("www.").unwrap_or(url);
+    let url = url.trim_end_matches('/');
+    url.strip_suffix(".git").unwrap_or(url).to_string()
+}
+
+fn normalize_name(name: &str) -> Vec<char> {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Sørensen–Dice coefficient over character bigrams of the alphanumeric,
+/// lowercased text.
+fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (normalize_name(a), normalize_name(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+    if a.len() < 2 || b.len() < 2 {
+        return 0.0;
+    }
+    let mut b_pairs: Vec<(char, char)> = b.windows(2).map(|w| (w[0], w[1])).collect();
+    let a_pairs = a.len() - 1;
+    let total = a_pairs + b_pairs.len();
+    let mut common = 0;
+    for w in a.windows(2) {
+        if let Some(i) = b_pairs.iter().position(|p| *p == (w[0], w[1])) {
+            b_pairs.swap_remove(i);
+            common += 1;
+        }
+    }
+    2.0 * common as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_match_projects() {
+        let project = r###"
+        {
+            "_id": "Wnxd13zP",
+            "slug": "clumps",
+            "title": "Clumps",
+            "source_url": "https://github.com/jaredlll08/Clumps",
+            "team": "3TidTIHz",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+        let version = r###"
+        {
+            "_id": "1ZHtT6Xo",
+            "project_id": "Wnxd13zP",
+            "author_id": "l45nT5ov",
+            "date_published": { "$date": "2024-12-22T23:43:33Z" },
+            "downloads": 0,
+            "files": [
+                {
+                    "hashes": {
+                        "sha512": "86909659af2f4b481ae9b230996e86658e622424e28b808d069144bf116bf47191df74cfec8b88bbc37ec9ad8cf5a4a24a0f21b39d6c456132331881c8575aeb",
+                        "sha1": "d1f522452cfa1286349525ccace065a8ec7eb940"
+                    },
+                    "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/1ZHtT6Xo/Clumps-fabric-1.21.4-22.0.0.1.jar",
+                    "filename": "Clumps-fabric-1.21.4-22.0.0.1.jar",
+                    "primary": true,
+                    "size": 20721,
+                    "file_type": null
+                }
+            ],
+            "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+        }
+        "###;
+        let same = r###"
+        {
+            "_id": 256717,
+            "slug": "clumps",
+            "name": "Clumps",
+            "links": { "sourceUrl": "https://github.com/jaredlll08/Clumps/" },
+            "latestFiles": [
+                {
+                    "id": 6003450,
+                    "gameId": 432,
+                    "modId": 256717,
+                    "hashes": [
+                        { "value": "D1F522452CFA1286349525CCACE065A8EC7EB940", "algo": 1 },
+                        { "value": "40d80421bbac8442b7a88db8add73003", "algo": 2 }
+                    ]
+                }
+            ],
+            "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+        }
+        "###;
+        let other = r###"
+        {
+            "_id": 594678,
+            "slug": "hats-and-cosmetics",
+            "name": "Hats and Cosmetics",
+            "links": { "sourceUrl": "https://github.com/PurplikDev/Hats-and-Cosmetics" },
+            "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+        }
+        "###;
+
+        let project: modrinth::Project = serde_json::from_str(project).expect("project");
+        let versions = vec![serde_json::from_str::<modrinth::Version>(version).expect("version")];
+        let candidates = vec![
+            serde_json::from_str::<curseforge::Mod>(other).expect("mod"),
+            serde_json::from_str::<curseforge::Mod>(same).expect("mod"),
+        ];
+
+        let found = best_match(&project, &versions, &candidates, &[], 0.5).expect("match");
+        assert_eq!(found.curseforge_id, 256717);
+        assert!(found.confidence > 0.99);
+        assert!(found.evidence.contains(&MatchEvidence::SharedFileHash {
+            sha1: "d1f522452cfa1286349525ccace065a8ec7eb940".to_string()
+        }));
+        assert!(found.evidence.contains(&MatchEvidence::SameSourceUrl {
+            url: "github.com/jaredlll08/clumps".to_string()
+        }));
+
+        let unrelated = score(&project, &versions, &candidates[0], &[]);
+        assert!(unrelated.evidence.is_empty());
+        assert_eq!(unrelated.confidence, 0.0);
+
+        let by_name_only = score(&project, &[], &candidates[1], &[]);
+        assert!(by_name_only.confidence > 0.5 && by_name_only.confidence < 0.99);
+        assert!(similarity("YUNG's API", "yungs-api") == 1.0);
+    }
+}
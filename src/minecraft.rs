@@ -0,0 +1,871 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::curseforge;
+use crate::modrinth::{self, GameVersion};
+
+/// Last weekly snapshot (year, week) leading up to each release, used to
+/// place `YYwWWx` snapshots among releases when no dates are available.
+/// Needs a new entry for every release that has weekly snapshots; until
+/// then, snapshots past the last entry are placed with its release.
+/// `McVersionCatalog` orders snapshots by date and does not depend on it.
+const SNAPSHOT_TARGETS: &[(u32, u32, &[u32])] = &[
+    (12, 8, &[1, 2]),
+    (12, 30, &[1, 3]),
+    (12, 42, &[1, 4]),
+    (12, 50, &[1, 4, 6]),
+    (13, 10, &[1, 5]),
+    (13, 26, &[1, 6]),
+    (13, 43, &[1, 7]),
+    (13, 49, &[1, 7, 4]),
+    (14, 34, &[1, 8]),
+    (16, 7, &[1, 9]),
+    (16, 15, &[1, 9, 3]),
+    (16, 21, &[1, 10]),
+    (16, 44, &[1, 11]),
+    (16, 50, &[1, 11, 1]),
+    (17, 18, &[1, 12]),
+    (17, 31, &[1, 12, 1]),
+    (18, 22, &[1, 13]),
+    (18, 33, &[1, 13, 1]),
+    (19, 14, &[1, 14]),
+    (19, 46, &[1, 15]),
+    (20, 22, &[1, 16]),
+    (20, 30, &[1, 16, 2]),
+    (21, 20, &[1, 17]),
+    (21, 44, &[1, 18]),
+    (22, 7, &[1, 18, 2]),
+    (22, 19, &[1, 19]),
+    (22, 24, &[1, 19, 1]),
+    (22, 46, &[1, 19, 3]),
+    (23, 7, &[1, 19, 4]),
+    (23, 18, &[1, 20]),
+    (23, 35, &[1, 20, 2]),
+    (23, 46, &[1, 20, 3]),
+    (24, 14, &[1, 20, 5]),
+    (24, 21, &[1, 21]),
+    (24, 40, &[1, 21, 2]),
+    (24, 46, &[1, 21, 4]),
+    (25, 10, &[1, 21, 5]),
+    (25, 21, &[1, 21, 6]),
+    (25, 37, &[1, 21, 9]),
+    (25, 46, &[1, 21, 11]),
+];
+
+/// Development eras before 1.0, oldest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Era {
+    PreClassic,
+    Classic,
+    Indev,
+    Infdev,
+    Alpha,
+    Beta,
+}
+
+/// Pre-release stage of a numbered version, least mature first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    /// `26.1-snapshot-1`
+    Snapshot(u32),
+    /// `1.20.5-pre1`, `1.14 Pre-Release 1`
+    PreRelease(u32),
+    /// `1.19-rc2`
+    ReleaseCandidate(u32),
+    Release,
+}
+
+#[derive(Debug, Clone)]
+pub enum McVersionKind {
+    Numbered {
+        base: Vec<u32>,
+        stage: Stage,
+    },
+    /// `25w04a`, also April Fools names like `24w14potato`.
+    WeeklySnapshot {
+        year: u32,
+        week: u32,
+        tag: String,
+    },
+    /// `a1.2.6`, `b1.7.3`, `inf-20100618`, `c0.30_01c`, `rd-132211`...
+    Old {
+        era: Era,
+        parts: Vec<Chunk>,
+    },
+}
+
+/// A run of digits or of other characters, compared naturally.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Chunk {
+    Text(String),
+    Number(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMcVersion(pub String);
+
+impl std::fmt::Display for InvalidMcVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unrecognized Minecraft version {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidMcVersion {}
+
+/// A Minecraft version id. Ordering is by version, not by string, and
+/// `1.20` equals `1.20.0`. Weekly snapshots are placed before the release
+/// they led up to; use `McVersionCatalog` for date-accurate ordering.
+#[derive(Debug, Clone)]
+pub struct McVersion {
+    raw: String,
+    kind: McVersionKind,
+}
+
+fn split_chunks(value: &str) -> Vec<Chunk> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut digits = false;
+    let mut flush = |current: &mut String, digits: bool| {
+        if current.is_empty() {
+            return;
+        }
+        let chunk = match current.parse::<u64>() {
+            Ok(n) if digits => Chunk::Number(n),
+            _ => Chunk::Text(current.to_ascii_lowercase()),
+        };
+        chunks.push(chunk);
+        current.clear();
+    };
+    for c in value.chars() {
+        if matches!(c, '.' | '-' | '_' | ' ') {
+            flush(&mut current, digits);
+            continue;
+        }
+        if c.is_ascii_digit() != digits {
+            flush(&mut current, digits);
+            digits = c.is_ascii_digit();
+        }
+        current.push(c);
+    }
+    flush(&mut current, digits);
+    chunks
+}
+
+fn parse_numbers(value: &str) -> Option<Vec<u32>> {
+    value
+        .split('.')
+        .map(|p| {
+            if p.is_empty() || !p.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            p.parse().ok()
+        })
+        .collect()
+}
+
+fn parse_stage(suffix: &str) -> Option<Stage> {
+    let suffix = suffix.trim_start_matches(['-', ' ']).to_ascii_lowercase();
+    let number = |rest: &str| -> Option<u32> {
+        let rest = rest.trim_start_matches(['-', ' ']);
+        if rest.is_empty() {
+            return Some(1);
+        }
+        rest.parse().ok()
+    };
+    if let Some(rest) = suffix.strip_prefix("snapshot") {
+        return number(rest).map(Stage::Snapshot);
+    }
+    if let Some(rest) = suffix
+        .strip_prefix("pre-release")
+        .or_else(|| suffix.strip_prefix("pre"))
+    {
+        return number(rest).map(Stage::PreRelease);
+    }
+    if let Some(rest) = suffix.strip_prefix("rc") {
+        return number(rest).map(Stage::ReleaseCandidate);
+    }
+    None
+}
+
+impl McVersion {
+    pub fn parse(value: &str) -> Result<Self, InvalidMcVersion> {
+        let raw = value.trim();
+        let invalid = || InvalidMcVersion(value.to_string());
+        let kind = Self::parse_kind(raw).ok_or_else(invalid)?;
+        Ok(McVersion {
+            raw: raw.to_string(),
+            kind,
+        })
+    }
+
+    fn parse_kind(raw: &str) -> Option<McVersionKind> {
+        if raw.is_empty() {
+            return None;
+        }
+
+        if let Some((year, rest)) = raw.split_once('w')
+            && year.len() == 2
+            && rest.len() > 2
+            && rest.is_char_boundary(2)
+        {
+            let (week, tag) = rest.split_at(2);
+            if let (Ok(year), Ok(week)) = (year.parse(), week.parse())
+                && !tag.is_empty()
+                && !tag.starts_with(|c: char| c.is_ascii_digit())
+            {
+                return Some(McVersionKind::WeeklySnapshot {
+                    year,
+                    week,
+                    tag: tag.to_string(),
+                });
+            }
+        }
+
+        let old = [
+            ("rd-", Era::PreClassic),
+            ("inf-", Era::Infdev),
+            ("in-", Era::Indev),
+            ("c", Era::Classic),
+            ("a", Era::Alpha),
+            ("b", Era::Beta),
+        ];
+        for (prefix, era) in old {
+            if let Some(rest) = raw.strip_prefix(prefix)
+                && rest.starts_with(|c: char| c.is_ascii_digit())
+            {
+                return Some(McVersionKind::Old {
+                    era,
+                    parts: split_chunks(rest),
+                });
+            }
+        }
+
+        let split = raw
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(raw.len());
+        let (numbers, suffix) = raw.split_at(split);
+        let base = parse_numbers(numbers)?;
+        let stage = if suffix.is_empty() {
+            Stage::Release
+        } else {
+            parse_stage(suffix)?
+        };
+        Some(McVersionKind::Numbered { base, stage })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn kind(&self) -> &McVersionKind {
+        &self.kind
+    }
+
+    pub fn is_release(&self) -> bool {
+        matches!(
+            self.kind,
+            McVersionKind::Numbered {
+                stage: Stage::Release,
+                ..
+            }
+        )
+    }
+
+    /// Release components for numbered versions, e.g. `[1, 20, 5]` for
+    /// `1.20.5-pre1`.
+    pub fn release_components(&self) -> Option<&[u32]> {
+        match &self.kind {
+            McVersionKind::Numbered { base, .. } => Some(base),
+            _ => None,
+        }
+    }
+
+    /// Trailing zeros trimmed so `1.20` and `1.20.0` compare equal.
+    fn base(&self) -> &[u32] {
+        let base: &[u32] = match &self.kind {
+            McVersionKind::Numbered { base, .. } => base,
+            McVersionKind::WeeklySnapshot { year, week, .. } => SNAPSHOT_TARGETS
+                .iter()
+                .find(|(y, w, _)| (*year, *week) <= (*y, *w))
+                .or(SNAPSHOT_TARGETS.last())
+                .map_or(&[], |(_, _, target)| *target),
+            McVersionKind::Old { .. } => &[],
+        };
+        let end = base.iter().rposition(|n| *n != 0).map_or(0, |i| i + 1);
+        &base[..end]
+    }
+
+    fn era(&self) -> Option<Era> {
+        match &self.kind {
+            McVersionKind::Old { era, .. } => Some(*era),
+            _ => None,
+        }
+    }
+}
+
+impl Ord for McVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Old eras sort before everything numbered (`None` is greatest here).
+        let era = match (self.era(), other.era()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        era.then_with(|| self.base().cmp(other.base()))
+            .then_with(|| match (&self.kind, &other.kind) {
+                (McVersionKind::Old { parts: a, .. }, McVersionKind::Old { parts: b, .. }) => {
+                    a.cmp(b)
+                }
+                (
+                    McVersionKind::WeeklySnapshot {
+                        year: ya,
+                        week: wa,
+                        tag: ta,
+                    },
+                    McVersionKind::WeeklySnapshot {
+                        year: yb,
+                        week: wb,
+                        tag: tb,
+                    },
+                ) => (ya, wa, ta).cmp(&(yb, wb, tb)),
+                (McVersionKind::WeeklySnapshot { .. }, _) => Ordering::Less,
+                (_, McVersionKind::WeeklySnapshot { .. }) => Ordering::Greater,
+                (
+                    McVersionKind::Numbered { stage: a, .. },
+                    McVersionKind::Numbered { stage: b, .. },
+                ) => a.cmp(b),
+                _ => Ordering::Equal,
+            })
+    }
+}
+
+impl PartialOrd for McVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for McVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for McVersion {}
+
+impl Hash for McVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.era().hash(state);
+        self.base().hash(state);
+        match &self.kind {
+            McVersionKind::Numbered { stage, .. } => stage.hash(state),
+            McVersionKind::WeeklySnapshot { year, week, tag } => (year, week, tag).hash(state),
+            McVersionKind::Old { parts, .. } => parts.hash(state),
+        }
+    }
+}
+
+impl std::fmt::Display for McVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl std::str::FromStr for McVersion {
+    type Err = InvalidMcVersion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        McVersion::parse(s)
+    }
+}
+
+impl Serialize for McVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for McVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        McVersion::parse(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl GameVersion {
+    pub fn mc_version(&self) -> Result<McVersion, InvalidMcVersion> {
+        McVersion::parse(&self.version)
+    }
+}
+
+/// Release dates from stored `GameVersion` records. Versions missing from
+/// the catalog sort right after the closest older version that is in it.
+#[derive(Debug, Clone, Default)]
+pub struct McVersionCatalog {
+    dates: HashMap<McVersion, DateTime<Utc>>,
+    by_version: Vec<(McVersion, DateTime<Utc>)>,
+}
+
+impl McVersionCatalog {
+    pub fn new(game_versions: &[GameVersion]) -> Self {
+        let mut dates = HashMap::new();
+        for gv in game_versions {
+            if let Ok(version) = gv.mc_version() {
+                dates.insert(version, gv.date);
+            }
+        }
+        let mut by_version: Vec<(McVersion, DateTime<Utc>)> =
+            dates.iter().map(|(v, d)| (v.clone(), *d)).collect();
+        by_version.sort_by(|a, b| a.0.cmp(&b.0));
+        McVersionCatalog { dates, by_version }
+    }
+
+    pub fn date(&self, version: &McVersion) -> Option<DateTime<Utc>> {
+        self.dates.get(version).copied()
+    }
+
+    pub fn contains(&self, version: &McVersion) -> bool {
+        self.dates.contains_key(version)
+    }
+
+    /// Catalogued versions use their release date, other weekly snapshots
+    /// the Monday of the week they are named after.
+    fn sort_key(&self, version: &McVersion) -> DateTime<Utc> {
+        if let Some(date) = self.date(version) {
+            return date;
+        }
+        if let McVersionKind::WeeklySnapshot { year, week, .. } = &version.kind
+            && let Some(day) = NaiveDate::from_isoywd_opt(2000 + *year as i32, *week, Weekday::Mon)
+        {
+            return day.and_time(NaiveTime::MIN).and_utc();
+        }
+        let below = self.by_version.partition_point(|(v, _)| v < version);
+        below
+            .checked_sub(1)
+            .map(|i| self.by_version[i].1)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+
+    pub fn cmp(&self, a: &McVersion, b: &McVersion) -> Ordering {
+        self.sort_key(a)
+            .cmp(&self.sort_key(b))
+            .then_with(|| a.cmp(b))
+    }
+
+    pub fn sort(&self, versions: &mut [McVersion]) {
+        versions.sort_by_cached_key(|v| (self.sort_key(v), v.clone()));
+    }
+
+    pub fn latest<'a>(&self, versions: &'a [McVersion]) -> Option<&'a McVersion> {
+        versions.iter().max_by(|a, b| self.cmp(a, b))
+    }
+}
+
+/// One bound of a `VersionRange`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Comparator {
+    Exact(McVersion),
+    /// `1.20.x`: any release whose components start with these.
+    Family(Vec<u32>),
+    Greater(McVersion),
+    GreaterOrEqual(McVersion),
+    Less(McVersion),
+    LessOrEqual(McVersion),
+}
+
+impl Comparator {
+    pub fn matches(&self, version: &McVersion) -> bool {
+        match self {
+            Comparator::Exact(v) => version == v,
+            Comparator::Family(prefix) => {
+                version.is_release()
+                    && version.release_components().is_some_and(|base| {
+                        prefix
+                            .iter()
+                            .enumerate()
+                            .all(|(i, n)| base.get(i).copied().unwrap_or(0) == *n)
+                    })
+            }
+            Comparator::Greater(v) => version > v,
+            Comparator::GreaterOrEqual(v) => version >= v,
+            Comparator::Less(v) => version < v,
+            Comparator::LessOrEqual(v) => version <= v,
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        let (op, rest) = match value.find(|c: char| !matches!(c, '<' | '>' | '=')) {
+            Some(i) => value.split_at(i),
+            None => (value, ""),
+        };
+        if !op.is_empty() {
+            let version = McVersion::parse(rest).ok()?;
+            return match op {
+                ">=" => Some(Comparator::GreaterOrEqual(version)),
+                "<=" => Some(Comparator::LessOrEqual(version)),
+                ">" => Some(Comparator::Greater(version)),
+                "<" => Some(Comparator::Less(version)),
+                "=" => Some(Comparator::Exact(version)),
+                _ => None,
+            };
+        }
+        if let Some(prefix) = value
+            .strip_suffix(".x")
+            .or_else(|| value.strip_suffix(".X"))
+            .or_else(|| value.strip_suffix(".*"))
+        {
+            return parse_numbers(prefix).map(Comparator::Family);
+        }
+        McVersion::parse(value).ok().map(Comparator::Exact)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidVersionRange(pub String);
+
+impl std::fmt::Display for InvalidVersionRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid game version range {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidVersionRange {}
+
+/// A set of game versions such as `1.20.1`, `1.20.x`, `>=1.19.2, <1.21`
+/// or `1.19 || 1.20.x`. Comparators separated by commas or spaces must all
+/// match; `||` separates alternatives. `*` matches everything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRange {
+    raw: String,
+    alternatives: Vec<Vec<Comparator>>,
+}
+
+impl VersionRange {
+    pub fn parse(value: &str) -> Result<Self, InvalidVersionRange> {
+        let invalid = || InvalidVersionRange(value.to_string());
+        let raw = value.trim();
+        if raw.is_empty() {
+            return Err(invalid());
+        }
+        let mut alternatives = Vec::new();
+        for alternative in raw.split("||") {
+            // Allow `>= 1.19` by gluing operators to the following version.
+            let mut comparators = Vec::new();
+            let mut pending = String::new();
+            for token in alternative.split([',', ' ']).filter(|t| !t.is_empty()) {
+                pending.push_str(token);
+                if matches!(pending.as_str(), ">=" | "<=" | ">" | "<" | "=") {
+                    continue;
+                }
+                if pending != "*" {
+                    comparators.push(Comparator::parse(&pending).ok_or_else(invalid)?);
+                }
+                pending.clear();
+            }
+            if !pending.is_empty() || (comparators.is_empty() && !alternative.contains('*')) {
+                return Err(invalid());
+            }
+            alternatives.push(comparators);
+        }
+        Ok(VersionRange {
+            raw: raw.to_string(),
+            alternatives,
+        })
+    }
+
+    /// Every release of a major version, the way `GameVersion.major`
+    /// groups them: `1.20` covers `1.20`, `1.20.1`, ...
+    pub fn major(version: &McVersion) -> Option<Self> {
+        let base = version.release_components()?;
+        let raw = format!("{}.x", version.as_str());
+        Some(VersionRange {
+            raw,
+            alternatives: vec![vec![Comparator::Family(base.to_vec())]],
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn matches(&self, version: &McVersion) -> bool {
+        self.alternatives
+            .iter()
+            .any(|all| all.iter().all(|c| c.matches(version)))
+    }
+
+    /// Strings that are not game versions (loaders, "Client"...) never match.
+    pub fn matches_str(&self, version: &str) -> bool {
+        McVersion::parse(version).is_ok_and(|v| self.matches(&v))
+    }
+
+    pub fn matches_any<S: AsRef<str>>(&self, versions: &[S]) -> bool {
+        versions.iter().any(|v| self.matches_str(v.as_ref()))
+    }
+}
+
+impl std::fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl std::str::FromStr for VersionRange {
+    type Err = InvalidVersionRange;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        VersionRange::parse(s)
+    }
+}
+
+impl Serialize for VersionRange {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionRange {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        VersionRange::parse(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl GameVersion {
+    /// The range of all releases in this major version, if it is one.
+    pub fn major_range(&self) -> Option<VersionRange> {
+        if !self.major {
+            return None;
+        }
+        VersionRange::major(&self.mc_version().ok()?)
+    }
+}
+
+impl modrinth::Project {
+    pub fn supports(&self, range: &VersionRange) -> bool {
+        range.matches_any(self.game_versions.as_deref().unwrap_or_default())
+    }
+}
+
+impl modrinth::Version {
+    pub fn supports(&self, range: &VersionRange) -> bool {
+        range.matches_any(self.game_versions.as_deref().unwrap_or_default())
+    }
+}
+
+impl curseforge::File {
+    pub fn supports(&self, range: &VersionRange) -> bool {
+        range.matches_any(self.game_versions.as_deref().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn v(value: &str) -> McVersion {
+        McVersion::parse(value).expect("valid version")
+    }
+
+    #[test]
+    fn test_mc_version_parse() {
+        assert!(v("1.21.4").is_release());
+        assert_eq!(v("1.20.5-pre1").release_components(), Some(&[1, 20, 5][..]));
+        assert!(matches!(
+            v("1.14 Pre-Release 1").kind(),
+            McVersionKind::Numbered {
+                stage: Stage::PreRelease(1),
+                ..
+            }
+        ));
+        assert!(matches!(
+            v("26.1-snapshot-2").kind(),
+            McVersionKind::Numbered {
+                stage: Stage::Snapshot(2),
+                ..
+            }
+        ));
+        assert!(matches!(
+            v("25w04a").kind(),
+            McVersionKind::WeeklySnapshot {
+                year: 25,
+                week: 4,
+                ..
+            }
+        ));
+        assert!(matches!(
+            v("b1.7.3").kind(),
+            McVersionKind::Old { era: Era::Beta, .. }
+        ));
+        assert!(McVersion::parse("Forge").is_err());
+        assert!(McVersion::parse("1.20-banana").is_err());
+        assert!(McVersion::parse("").is_err());
+    }
+
+    #[test]
+    fn test_mc_version_ordering() {
+        let mut versions: Vec<McVersion> = [
+            "1.21.4",
+            "26.1",
+            "1.20",
+            "25w04a",
+            "1.19-rc2",
+            "rd-132211",
+            "1.20.5-pre1",
+            "1.20.5",
+            "b1.7.3",
+            "1.19",
+            "a1.2.6",
+            "24w14a",
+            "26.1-snapshot-1",
+            "1.2.5",
+            "c0.30_01c",
+            "inf-20100618",
+            "1.21.5",
+        ]
+        .iter()
+        .map(|s| v(s))
+        .collect();
+        versions.sort();
+        let sorted: Vec<&str> = versions.iter().map(|v| v.as_str()).collect();
+        assert_eq!(
+            sorted,
+            [
+                "rd-132211",
+                "c0.30_01c",
+                "inf-20100618",
+                "a1.2.6",
+                "b1.7.3",
+                "1.2.5",
+                "1.19-rc2",
+                "1.19",
+                "1.20",
+                "24w14a",
+                "1.20.5-pre1",
+                "1.20.5",
+                "1.21.4",
+                "25w04a",
+                "1.21.5",
+                "26.1-snapshot-1",
+                "26.1",
+            ]
+        );
+
+        assert_eq!(v("1.20"), v("1.20.0"));
+        let mut counts: HashMap<McVersion, u32> = HashMap::new();
+        *counts.entry(v("1.20")).or_default() += 1;
+        *counts.entry(v("1.20.0")).or_default() += 1;
+        assert_eq!(counts[&v("1.20")], 2);
+
+        let parsed: Vec<McVersion> =
+            serde_json::from_str(r#"["1.21.4", "25w04a"]"#).expect("deserialize versions");
+        assert_eq!(parsed[1].to_string(), "25w04a");
+    }
+
+    #[test]
+    fn test_mc_version_catalog() {
+        let json = r###"
+        [
+            {
+                "version": "1.21.4",
+                "version_type": "release",
+                "date": { "$date": "2024-12-03T10:12:57Z" },
+                "major": false,
+                "sync_at": { "$date": "2025-01-26T09:11:24.542Z" }
+            },
+            {
+                "version": "99w01a",
+                "version_type": "snapshot",
+                "date": { "$date": "2025-01-08T13:14:44Z" },
+                "major": false,
+                "sync_at": { "$date": "2025-01-26T09:11:24.542Z" }
+            },
+            {
+                "version": "1.21.5",
+                "version_type": "release",
+                "date": { "$date": "2025-03-25T12:14:58Z" },
+                "major": false,
+                "sync_at": { "$date": "2025-01-26T09:11:24.542Z" }
+            }
+        ]
+        "###;
+        let game_versions: Vec<GameVersion> =
+            serde_json::from_str(json).expect("deserialize game versions from json");
+        let catalog = McVersionCatalog::new(&game_versions);
+
+        // Past the built-in table, so structurally it is placed with the
+        // last release there; the catalog date puts it before 1.21.5.
+        assert!(v("99w01a") > v("1.21.5"));
+        assert!(v("99w01a") < v("1.21.11"));
+        assert_eq!(catalog.cmp(&v("99w01a"), &v("1.21.5")), Ordering::Less);
+
+        // Uncatalogued snapshots are placed by the week they are named after.
+        assert_eq!(catalog.cmp(&v("24w50a"), &v("1.21.4")), Ordering::Greater);
+        assert_eq!(catalog.cmp(&v("25w10a"), &v("1.21.5")), Ordering::Less);
+        assert_eq!(catalog.cmp(&v("25w14a"), &v("1.21.5")), Ordering::Greater);
+
+        let mut versions = vec![v("1.21.5"), v("1.21.4.1"), v("99w01a"), v("1.21.4")];
+        catalog.sort(&mut versions);
+        let sorted: Vec<&str> = versions.iter().map(|v| v.as_str()).collect();
+        assert_eq!(sorted, ["1.21.4", "1.21.4.1", "99w01a", "1.21.5"]);
+        assert_eq!(
+            catalog.latest(&versions).map(|v| v.as_str()),
+            Some("1.21.5")
+        );
+    }
+
+    #[test]
+    fn test_version_range() {
+        let range = VersionRange::parse(">=1.19.2, <1.21").expect("range");
+        assert!(range.matches(&v("1.19.2")));
+        assert!(range.matches(&v("1.20.6")));
+        assert!(!range.matches(&v("1.19.1")));
+        assert!(!range.matches(&v("1.21")));
+        assert!(range.matches(&v("1.21-pre1")));
+
+        let family = VersionRange::parse("1.20.x").expect("range");
+        assert!(family.matches(&v("1.20")));
+        assert!(family.matches(&v("1.20.4")));
+        assert!(!family.matches(&v("1.20.5-pre1")));
+        assert!(!family.matches(&v("1.21")));
+
+        let either = VersionRange::parse("1.18.2 || >= 1.20").expect("range");
+        assert!(either.matches(&v("1.18.2")));
+        assert!(either.matches(&v("26.1")));
+        assert!(!either.matches(&v("1.19.4")));
+        assert!(
+            VersionRange::parse("*")
+                .expect("range")
+                .matches(&v("b1.7.3"))
+        );
+
+        assert!(VersionRange::parse("").is_err());
+        assert!(VersionRange::parse(">=").is_err());
+        assert!(VersionRange::parse("1.20, Forge").is_err());
+
+        let major = GameVersion {
+            version: "1.20".to_string(),
+            version_type: "release".to_string(),
+            date: Utc::now(),
+            major: true,
+            sync_at: Utc::now(),
+        };
+        let range = major.major_range().expect("major range");
+        assert_eq!(range.as_str(), "1.20.x");
+        assert!(range.matches_any(&["Forge", "1.20.1"]));
+        assert!(!range.matches_any(&["Forge", "1.19.1"]));
+
+        let file: curseforge::File = serde_json::from_str(
+            r#"{"id": 4012345, "gameId": 432, "modId": 594678, "gameVersions": ["Forge", "1.19.1"], "sync_at": {"$date": "2025-06-17T10:46:29.504Z"}}"#,
+        )
+        .expect("deserialize file");
+        assert!(file.supports(&VersionRange::parse("1.19.x").expect("range")));
+        assert!(!file.supports(&range));
+    }
+}
@@ -0,0 +1,311 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use bson::serde_helpers::datetime::FromChrono04DateTime;
+use bson::{Bson, Document};
+
+use crate::{curseforge, modrinth};
+
+/// The `found: false` flag of a negative-cache entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NotFound;
+
+impl Serialize for NotFound {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(false)
+    }
+}
+
+impl<'de> Deserialize<'de> for NotFound {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match bool::deserialize(deserializer)? {
+            false => Ok(NotFound),
+            true => Err(serde::de::Error::custom("document is not a missing entry")),
+        }
+    }
+}
+
+/// Negative-cache entry for an id or hash upstream reported as missing.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct Missing {
+    found: NotFound,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl Missing {
+    pub fn new(sync_at: DateTime<Utc>) -> Self {
+        Missing {
+            found: NotFound,
+            sync_at,
+        }
+    }
+}
+
+/// A stored document: either the synced model or a `found: false` entry.
+/// The `found` flag decides which one is read, and documents without it
+/// are models. Models never carry the flag themselves; it is written here.
+#[derive(Debug, Clone)]
+pub enum MirrorRecord<T> {
+    Missing(Missing),
+    Found(T),
+}
+
+impl<T: Serialize> Serialize for MirrorRecord<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Found<'a, T> {
+            #[serde(flatten)]
+            value: &'a T,
+            found: bool,
+        }
+
+        match self {
+            MirrorRecord::Missing(missing) => missing.serialize(serializer),
+            MirrorRecord::Found(value) => Found { value, found: true }.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for MirrorRecord<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let document = Document::deserialize(deserializer)?;
+        let found = match document.get("found") {
+            None => true,
+            Some(Bson::Boolean(found)) => *found,
+            Some(other) => {
+                return Err(D::Error::custom(format!(
+                    "`found` must be a boolean, got {other}"
+                )));
+            }
+        };
+        if found {
+            bson::deserialize_from_document(document)
+                .map(MirrorRecord::Found)
+                .map_err(|e| D::Error::custom(format!("invalid found document: {e}")))
+        } else {
+            bson::deserialize_from_document(document)
+                .map(MirrorRecord::Missing)
+                .map_err(|e| D::Error::custom(format!("invalid missing entry: {e}")))
+        }
+    }
+}
+
+impl<T> MirrorRecord<T> {
+    pub fn found(self) -> Option<T> {
+        match self {
+            MirrorRecord::Found(value) => Some(value),
+            MirrorRecord::Missing(_) => None,
+        }
+    }
+}
+
+/// Result of looking an id up in the mirror, including ids it has never
+/// synced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lookup<T> {
+    Found(T),
+    Missing { checked_at: DateTime<Utc> },
+    NotSynced,
+}
+
+impl<T> Lookup<T> {
+    pub fn found(self) -> Option<T> {
+        match self {
+            Lookup::Found(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_ref(&self) -> Lookup<&T> {
+        match self {
+            Lookup::Found(value) => Lookup::Found(value),
+            Lookup::Missing { checked_at } => Lookup::Missing {
+                checked_at: *checked_at,
+            },
+            Lookup::NotSynced => Lookup::NotSynced,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Lookup<U> {
+        match self {
+            Lookup::Found(value) => Lookup::Found(f(value)),
+            Lookup::Missing { checked_at } => Lookup::Missing { checked_at },
+            Lookup::NotSynced => Lookup::NotSynced,
+        }
+    }
+
+    pub fn is_found(&self) -> bool {
+        matches!(self, Lookup::Found(_))
+    }
+
+    /// Whether upstream should be asked again: never-synced ids always,
+    /// missing entries once they are older than `max_age`.
+    pub fn needs_sync(&self, now: DateTime<Utc>, max_age: Duration) -> bool {
+        match self {
+            Lookup::Found(_) => false,
+            Lookup::Missing { checked_at } => now - *checked_at > max_age,
+            Lookup::NotSynced => true,
+        }
+    }
+}
+
+impl<T> From<MirrorRecord<T>> for Lookup<T> {
+    fn from(record: MirrorRecord<T>) -> Self {
+        match record {
+            MirrorRecord::Found(value) => Lookup::Found(value),
+            MirrorRecord::Missing(missing) => Lookup::Missing {
+                checked_at: missing.sync_at,
+            },
+        }
+    }
+}
+
+impl<T> From<Option<MirrorRecord<T>>> for Lookup<T> {
+    fn from(record: Option<MirrorRecord<T>>) -> Self {
+        record.map_or(Lookup::NotSynced, Lookup::from)
+    }
+}
+
+pub type ModrinthProjectRecord = MirrorRecord<modrinth::Project>;
+pub type ModrinthVersionRecord = MirrorRecord<modrinth::Version>;
+pub type ModrinthFileRecord = MirrorRecord<modrinth::File>;
+pub type CurseForgeModRecord = MirrorRecord<curseforge::Mod>;
+pub type CurseForgeFileRecord = MirrorRecord<curseforge::File>;
+pub type CurseForgeFingerprintRecord = MirrorRecord<curseforge::Fingerprint>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_mirror_record_found() {
+        let json = r###"
+        {
+            "_id": {
+                "sha512": "86909659af2f4b481ae9b230996e86658e622424e28b808d069144bf116bf47191df74cfec8b88bbc37ec9ad8cf5a4a24a0f21b39d6c456132331881c8575aeb",
+                "sha1": "d1f522452cfa1286349525ccace065a8ec7eb940"
+            },
+            "file_cdn_cached": false,
+            "file_type": null,
+            "filename": "Clumps-fabric-1.21.4-22.0.0.1.jar",
+            "found": true,
+            "primary": true,
+            "project_id": "Wnxd13zP",
+            "size": 20721,
+            "sync_at": {
+                "$date": "2024-12-31T15:01:35Z"
+            },
+            "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/1ZHtT6Xo/Clumps-fabric-1.21.4-22.0.0.1.jar",
+            "version_id": "1ZHtT6Xo"
+        }
+        "###;
+
+        let r: ModrinthFileRecord =
+            serde_json::from_str(json).expect("deserialize record from json");
+        let lookup = Lookup::from(Some(r));
+        assert!(lookup.is_found());
+        assert_eq!(
+            lookup.found().map(|f| f.version_id).as_deref(),
+            Some("1ZHtT6Xo")
+        );
+    }
+
+    #[test]
+    fn test_mirror_record_version_round_trip() {
+        let json = r###"
+        {
+            "_id": "1ZHtT6Xo",
+            "project_id": "Wnxd13zP",
+            "author_id": "l45nT5ov",
+            "date_published": { "$date": "2024-12-22T23:43:33Z" },
+            "downloads": 0,
+            "files": [],
+            "found": true,
+            "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+        }
+        "###;
+
+        let r: ModrinthVersionRecord =
+            serde_json::from_str(json).expect("deserialize record from json");
+        let serialized = serde_json::to_string(&r).expect("serialize record");
+        assert_eq!(serialized.matches("\"found\"").count(), 1);
+        let r: ModrinthVersionRecord =
+            serde_json::from_str(&serialized).expect("deserialize serialized record");
+        assert_eq!(r.found().map(|v| v.id).as_deref(), Some("1ZHtT6Xo"));
+    }
+
+    #[test]
+    fn test_mirror_record_missing() {
+        let json = r###"
+        {
+            "_id": 3913841,
+            "found": false,
+            "sync_at": {
+                "$date": "2024-10-26T12:33:55Z"
+            }
+        }
+        "###;
+
+        let r: CurseForgeFileRecord =
+            serde_json::from_str(json).expect("deserialize record from json");
+        let checked_at = "2024-10-26T12:33:55Z".parse::<DateTime<Utc>>().unwrap();
+        assert!(matches!(&r, MirrorRecord::Missing(m) if m.sync_at == checked_at));
+
+        let lookup = Lookup::from(Some(r));
+        assert_eq!(
+            lookup.as_ref().map(|f| f.id),
+            Lookup::Missing { checked_at }
+        );
+        assert!(!lookup.needs_sync(checked_at + Duration::hours(1), Duration::days(1)));
+        assert!(lookup.needs_sync(checked_at + Duration::days(2), Duration::days(1)));
+
+        let never: Lookup<curseforge::File> = Lookup::from(None);
+        assert_eq!(never.as_ref().map(|f| f.id), Lookup::NotSynced);
+    }
+
+    #[test]
+    fn test_curseforge_fingerprint_model() {
+        let json = r###"
+        {
+            "_id": 1221617322,
+            "file": {
+                "id": 3913840,
+                "gameId": 432,
+                "modId": 594678,
+                "fileName": "hats-and-cosmetics-1.2.2-1.19.1.jar",
+                "fileFingerprint": 1221617322
+            },
+            "latestFiles": [],
+            "found": true,
+            "sync_at": {
+                "$date": "2024-10-26T12:33:55Z"
+            }
+        }
+        "###;
+
+        let r: CurseForgeFingerprintRecord =
+            serde_json::from_str(json).expect("deserialize record from json");
+        let value = serde_json::to_value(&r).expect("serialize record");
+        assert_eq!(value["found"], true);
+        let r: CurseForgeFingerprintRecord =
+            serde_json::from_value(value).expect("deserialize serialized record");
+        let fp = r.found().expect("found fingerprint");
+        assert_eq!(fp.id, 1221617322);
+        assert_eq!(fp.file.id, 3913840);
+
+        // A broken model is reported as such, not as a failed `found: false`.
+        let broken = json.replace("\"latestFiles\": [],", "");
+        let err = serde_json::from_str::<CurseForgeFingerprintRecord>(&broken)
+            .expect_err("missing latestFiles");
+        assert!(err.to_string().contains("latestFiles"), "{err}");
+        assert!(
+            serde_json::from_str::<CurseForgeFingerprintRecord>(r#"{ "found": "no" }"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+
+use crate::minecraft::McVersion;
+use crate::{curseforge, modrinth};
+
+/// Prefixes that mark a number as a Minecraft version (`mc1.20.1`,
+/// `fabric1.20`) rather than the mod's own.
+const MINECRAFT_PREFIXES: &[&str] = &["mc", "minecraft", "forge", "neoforge", "fabric", "quilt"];
+
+/// Pre-release label following a version, least mature first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PreRelease {
+    Snapshot(u64),
+    Dev(u64),
+    Alpha(u64),
+    Beta(u64),
+    Pre(u64),
+    ReleaseCandidate(u64),
+}
+
+impl PreRelease {
+    fn parse(token: &str) -> Option<Self> {
+        let token = token.to_ascii_lowercase();
+        let split = token
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(token.len());
+        let (label, number) = token.split_at(split);
+        let label = label.trim_end_matches('.');
+        let number = if number.is_empty() {
+            0
+        } else {
+            number.parse().ok()?
+        };
+        match label {
+            "snapshot" => Some(PreRelease::Snapshot(number)),
+            "dev" => Some(PreRelease::Dev(number)),
+            "alpha" => Some(PreRelease::Alpha(number)),
+            "beta" => Some(PreRelease::Beta(number)),
+            "pre" => Some(PreRelease::Pre(number)),
+            "rc" => Some(PreRelease::ReleaseCandidate(number)),
+            _ => None,
+        }
+    }
+}
+
+/// A mod's own version extracted from a version number or file name such
+/// as `22.0.0.1`, `v2.3.1+mc1.20.1` or `hats-and-cosmetics-1.2.2-1.19.1`.
+/// Components are compared numerically with missing ones as zero, and a
+/// pre-release sorts before its release.
+#[derive(Debug, Clone)]
+pub struct ModVersion {
+    raw: String,
+    parts: Vec<u64>,
+    pre: Option<PreRelease>,
+}
+
+struct Candidate {
+    index: usize,
+    parts: Vec<u64>,
+    minecraft: bool,
+}
+
+fn parse_parts(value: &str) -> Option<Vec<u64>> {
+    if value.is_empty() {
+        return None;
+    }
+    value
+        .split('.')
+        .map(|p| {
+            if p.is_empty() || !p.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            p.parse().ok()
+        })
+        .collect()
+}
+
+/// `1.7` through `1.x` with at most three components; only used to break
+/// ties when no game versions are known.
+fn looks_like_minecraft(parts: &[u64]) -> bool {
+    parts.len() <= 3 && parts.len() >= 2 && parts[0] == 1 && parts[1] >= 7
+}
+
+impl ModVersion {
+    /// Parses without knowing which game versions the text may contain.
+    pub fn parse(text: &str) -> Option<Self> {
+        Self::extract::<&str>(text, &[])
+    }
+
+    /// Parses `text`, ignoring numbers equal to one of `game_versions`.
+    pub fn extract<S: AsRef<str>>(text: &str, game_versions: &[S]) -> Option<Self> {
+        let trimmed = text.trim();
+        let stem = trimmed
+            .strip_suffix(".jar")
+            .or_else(|| trimmed.strip_suffix(".zip"))
+            .unwrap_or(trimmed);
+        let tokens: Vec<&str> = stem
+            .split(['-', '_', '+', ' ', '(', ')', '[', ']'])
+            .filter(|t| !t.is_empty())
+            .collect();
+        let game_versions: Vec<McVersion> = game_versions
+            .iter()
+            .filter_map(|v| McVersion::parse(v.as_ref()).ok())
+            .collect();
+
+        let mut candidates = Vec::new();
+        for (index, token) in tokens.iter().enumerate() {
+            let lower = token.to_ascii_lowercase();
+            if let Some(parts) = parse_parts(&lower) {
+                let minecraft = McVersion::parse(&lower).is_ok_and(|v| game_versions.contains(&v));
+                candidates.push(Candidate {
+                    index,
+                    parts,
+                    minecraft,
+                });
+                continue;
+            }
+            if let Some(parts) = lower.strip_prefix('v').and_then(parse_parts) {
+                candidates.push(Candidate {
+                    index,
+                    parts,
+                    minecraft: false,
+                });
+                continue;
+            }
+            for prefix in MINECRAFT_PREFIXES {
+                if let Some(parts) = lower.strip_prefix(prefix).and_then(parse_parts) {
+                    candidates.push(Candidate {
+                        index,
+                        parts,
+                        minecraft: true,
+                    });
+                    break;
+                }
+            }
+        }
+
+        candidates.retain(|c| !c.minecraft);
+        if candidates.len() > 1
+            && game_versions.is_empty()
+            && candidates.iter().any(|c| !looks_like_minecraft(&c.parts))
+        {
+            candidates.retain(|c| !looks_like_minecraft(&c.parts));
+        }
+        let chosen = candidates.into_iter().next()?;
+
+        let pre = tokens
+            .get(chosen.index + 1)
+            .and_then(|t| PreRelease::parse(t));
+        Some(ModVersion {
+            raw: trimmed.to_string(),
+            parts: chosen.parts,
+            pre,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn parts(&self) -> &[u64] {
+        &self.parts
+    }
+
+    pub fn pre_release(&self) -> Option<PreRelease> {
+        self.pre
+    }
+
+    fn trimmed_parts(&self) -> &[u64] {
+        let end = self
+            .parts
+            .iter()
+            .rposition(|n| *n != 0)
+            .map_or(0, |i| i + 1);
+        &self.parts[..end]
+    }
+}
+
+impl Ord for ModVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.trimmed_parts()
+            .cmp(other.trimmed_parts())
+            .then_with(|| match (self.pre, other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(&b),
+            })
+    }
+}
+
+impl PartialOrd for ModVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ModVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ModVersion {}
+
+impl std::fmt::Display for ModVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+/// A release of one project: its mod version, if any, and publish date.
+pub type Release = (Option<ModVersion>, Option<DateTime<Utc>>);
+
+/// Sort key of a release among its project's releases, built by
+/// `release_keys`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReleaseKey {
+    version: Option<ModVersion>,
+    date: Option<DateTime<Utc>>,
+}
+
+/// Keys for `releases`, in input order. Versioned releases are ordered by
+/// version, then date. A release without a version takes the highest
+/// version published at or before it, so it sorts after that release and
+/// before any published later; with nothing published before it, it sorts
+/// first. Between two releases this is a plain date comparison whenever
+/// either lacks a version. Across more than two, date order can disagree
+/// with version order, and the key keeps the version order so the result
+/// stays a total order. Missing dates sort first.
+pub fn release_keys(releases: &[Release]) -> Vec<ReleaseKey> {
+    releases
+        .iter()
+        .map(|(version, date)| {
+            let version = match version {
+                Some(version) => Some(version.clone()),
+                None => releases
+                    .iter()
+                    .filter(|(v, d)| v.is_some() && d <= date)
+                    .filter_map(|(v, _)| v.clone())
+                    .max(),
+            };
+            ReleaseKey {
+                version,
+                date: *date,
+            }
+        })
+        .collect()
+}
+
+fn compare(a: Release, b: Release) -> Ordering {
+    let keys = release_keys(&[a, b]);
+    keys[0].cmp(&keys[1])
+}
+
+impl modrinth::Version {
+    /// From `version_number`, falling back to `name`.
+    pub fn mod_version(&self) -> Option<ModVersion> {
+        let game_versions = self.game_versions.as_deref().unwrap_or_default();
+        [&self.version_number, &self.name]
+            .into_iter()
+            .flatten()
+            .find_map(|text| ModVersion::extract(text, game_versions))
+    }
+
+    fn release(&self) -> Release {
+        (self.mod_version(), Some(self.date_published))
+    }
+
+    /// Compares two releases by `release_keys` over just the pair.
+    pub fn cmp_release(&self, other: &modrinth::Version) -> Ordering {
+        compare(self.release(), other.release())
+    }
+
+    /// The newest of `versions` by `release_keys` over all of them.
+    pub fn newest<'a>(
+        versions: impl IntoIterator<Item = &'a modrinth::Version>,
+    ) -> Option<&'a modrinth::Version> {
+        let versions: Vec<&modrinth::Version> = versions.into_iter().collect();
+        let releases: Vec<Release> = versions.iter().map(|v| v.release()).collect();
+        versions
+            .into_iter()
+            .zip(release_keys(&releases))
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(version, _)| version)
+    }
+}
+
+impl curseforge::File {
+    /// From `display_name`, falling back to `file_name`.
+    pub fn mod_version(&self) -> Option<ModVersion> {
+        let game_versions = self.game_version_tags().minecraft;
+        [&self.display_name, &self.file_name]
+            .into_iter()
+            .flatten()
+            .find_map(|text| ModVersion::extract(text, &game_versions))
+    }
+
+    fn release(&self) -> Release {
+        (self.mod_version(), self.file_date)
+    }
+
+    /// Compares two releases by `release_keys` over just the pair.
+    pub fn cmp_release(&self, other: &curseforge::File) -> Ordering {
+        compare(self.release(), other.release())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn parts(text: &str, game_versions: &[&str]) -> Vec<u64> {
+        ModVersion::extract(text, game_versions)
+            .expect("mod version")
+            .parts()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_mod_version_extract() {
+        assert_eq!(parts("22.0.0.1", &["1.21.4"]), [22, 0, 0, 1]);
+        assert_eq!(
+            parts("hats-and-cosmetics-1.2.2-1.19.1", &["Forge", "1.19.1"]),
+            [1, 2, 2]
+        );
+        assert_eq!(parts("hats-and-cosmetics-1.2.2-1.19.1", &[]), [1, 2, 2]);
+        assert_eq!(
+            parts("Clumps-fabric-1.21.4-22.0.0.1.jar", &[]),
+            [22, 0, 0, 1]
+        );
+        assert_eq!(parts("sodium-fabric-0.5.8+mc1.20.1", &[]), [0, 5, 8]);
+        assert_eq!(parts("v2.3.1+1.20.1", &["1.20.1"]), [2, 3, 1]);
+        assert_eq!(parts("jei-1.20.1-forge-15.2.0.27", &[]), [15, 2, 0, 27]);
+
+        let beta = ModVersion::parse("mymod-2.0.0-beta.3").expect("mod version");
+        assert_eq!(beta.pre_release(), Some(PreRelease::Beta(3)));
+        assert!(ModVersion::parse("Fabric API").is_none());
+        assert!(ModVersion::extract("1.19.1", &["1.19.1"]).is_none());
+    }
+
+    #[test]
+    fn test_mod_version_ordering() {
+        let v = |s: &str| ModVersion::parse(s).expect("mod version");
+        assert!(v("1.10.0") > v("1.9.3"));
+        assert!(v("2.0.0-rc1") < v("2.0.0"));
+        assert!(v("2.0.0-alpha") < v("2.0.0-beta"));
+        assert!(v("2.0.0-beta.2") < v("2.0.0-beta.10"));
+        assert_eq!(v("1.2"), v("1.2.0"));
+
+        let json = r###"
+        [
+            {
+                "id": 3913840,
+                "gameId": 432,
+                "modId": 594678,
+                "displayName": "hats-and-cosmetics-1.2.2-1.19.1",
+                "fileDate": { "$date": "2022-08-01T00:00:00Z" },
+                "gameVersions": ["Forge", "1.19.1"],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "id": 3872689,
+                "gameId": 432,
+                "modId": 594678,
+                "displayName": "hats-and-cosmetics-1.2.10-1.19",
+                "fileDate": { "$date": "2022-07-01T00:00:00Z" },
+                "gameVersions": ["Forge", "1.19"],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "id": 3872690,
+                "gameId": 432,
+                "modId": 594678,
+                "displayName": "Hats and Cosmetics",
+                "fileDate": { "$date": "2022-07-15T00:00:00Z" },
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            }
+        ]
+        "###;
+        let files: Vec<curseforge::File> =
+            serde_json::from_str(json).expect("deserialize files from json");
+        // Version wins over the older date...
+        assert_eq!(files[1].cmp_release(&files[0]), Ordering::Greater);
+        // ...and a name without a version falls back to the publish date.
+        assert_eq!(files[2].cmp_release(&files[1]), Ordering::Greater);
+        assert_eq!(files[2].cmp_release(&files[0]), Ordering::Less);
+
+        // Over all three, the unversioned release follows 1.2.10, the
+        // highest version published before it, so the order is consistent
+        // whichever way the input is arranged.
+        for order in [[0, 1, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]] {
+            let files: Vec<&curseforge::File> = order.iter().map(|&i| &files[i]).collect();
+            let releases: Vec<Release> = files.iter().map(|f| f.release()).collect();
+            let keys = release_keys(&releases);
+            let mut sorted: Vec<(&ReleaseKey, i32)> =
+                keys.iter().zip(files.iter().map(|f| f.id)).collect();
+            sorted.sort();
+            let ids: Vec<i32> = sorted.iter().map(|(_, id)| *id).collect();
+            assert_eq!(ids, [3913840, 3872689, 3872690]);
+        }
+    }
+}
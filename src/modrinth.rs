@@ -20,6 +20,21 @@ pub struct License {
     pub url: Option<String>,
 }
 
+impl License {
+    /// Upstream only sets `url` for custom licenses; SPDX licenses link to
+    /// their SPDX page instead.
+    pub fn effective_url(&self) -> Option<String> {
+        if let Some(url) = self.url.as_ref().filter(|u| !u.is_empty()) {
+            return Some(url.clone());
+        }
+        let id = self.id.as_deref()?;
+        if id.is_empty() || id.starts_with("LicenseRef-") {
+            return None;
+        }
+        Some(format!("https://spdx.org/licenses/{id}.html"))
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct GalleryItem {
@@ -32,6 +47,13 @@ pub struct GalleryItem {
     pub ordering: Option<i64>,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ModeratorMessage {
+    pub message: String,
+    pub body: Option<String>,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Project {
@@ -40,6 +62,7 @@ pub struct Project {
     pub slug: String,
     pub title: Option<String>,
     pub description: Option<String>,
+    pub translated_description: Option<String>,
     pub categories: Option<Vec<String>>,
     pub client_side: Option<String>,
     pub server_side: Option<String>,
@@ -59,6 +82,7 @@ pub struct Project {
     pub thread_id: Option<String>,
     pub monetization_status: Option<String>,
     pub team: String,
+    pub organization: Option<String>,
     pub body_url: Option<String>,
     #[serde_as(as = "FromChrono04DateTime")]
     pub published: DateTime<Utc>,
@@ -68,6 +92,7 @@ pub struct Project {
     pub approved: Option<DateTime<Utc>>,
     #[serde_as(as = "Option<FromChrono04DateTime>")]
     pub queued: Option<DateTime<Utc>>,
+    pub moderator_message: Option<ModeratorMessage>,
     pub followers: u32,
     pub license: Option<License>,
     pub versions: Option<Vec<String>>,
@@ -130,6 +155,7 @@ pub struct Version {
     #[serde(alias = "_id")]
     pub id: String,
     pub project_id: String,
+    pub slug: Option<String>,
     pub name: Option<String>,
     pub version_number: Option<String>,
     pub changelog: Option<String>,
@@ -138,6 +164,7 @@ pub struct Version {
     pub version_type: Option<String>,
     pub loaders: Option<Vec<String>>,
     pub featured: Option<bool>,
+    pub ordering: Option<i32>,
     pub status: Option<String>,
     pub requested_status: Option<String>,
     pub author_id: String,
@@ -182,8 +209,1222 @@ pub struct GameVersion {
     pub date: DateTime<Utc>,
     pub major: bool,
 
-    #[serde_as(as = "FromChrono04DateTime")]
-    pub sync_at: DateTime<Utc>,
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProjectHit {
+    pub project_id: String,
+    pub slug: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub categories: Vec<String>,
+    pub display_categories: Vec<String>,
+    pub client_side: Option<String>,
+    pub server_side: Option<String>,
+    pub project_type: Option<String>,
+    pub downloads: i64,
+    pub icon_url: Option<String>,
+    pub color: Option<u32>,
+    pub thread_id: Option<String>,
+    pub monetization_status: Option<String>,
+    pub author: String,
+    pub versions: Vec<String>,
+    pub follows: u32,
+    pub date_created: DateTime<Utc>,
+    pub date_modified: DateTime<Utc>,
+    pub latest_version: Option<String>,
+    pub license: Option<String>,
+    pub gallery: Vec<String>,
+    pub featured_gallery: Option<String>,
+}
+
+impl ProjectHit {
+    /// Builds a search hit from a stored project. `author` is the owner's
+    /// username, which the project document itself does not carry, and
+    /// `latest_version` is left for `with_latest_version`.
+    pub fn from_project(project: &Project, author: impl Into<String>) -> Self {
+        let categories = project.categories.clone().unwrap_or_default();
+        let loaders = project.loaders.clone().unwrap_or_default();
+        let additional = project.additional_categories.clone().unwrap_or_default();
+        let gallery = project.gallery.as_deref().unwrap_or_default();
+
+        let mut display_categories = categories.clone();
+        display_categories.extend(loaders.iter().cloned());
+
+        let mut all_categories = categories;
+        all_categories.extend(additional);
+        all_categories.extend(loaders);
+
+        ProjectHit {
+            project_id: project.id.clone(),
+            slug: project.slug.clone(),
+            title: project.title.clone(),
+            description: project.description.clone(),
+            categories: all_categories,
+            display_categories,
+            client_side: project.client_side.clone(),
+            server_side: project.server_side.clone(),
+            project_type: project.project_type.clone(),
+            downloads: project.downloads.unwrap_or(0),
+            icon_url: project.icon_url.clone(),
+            color: project.color,
+            thread_id: project.thread_id.clone(),
+            monetization_status: project.monetization_status.clone(),
+            author: author.into(),
+            versions: project.game_versions.clone().unwrap_or_default(),
+            follows: project.followers,
+            date_created: project.published,
+            date_modified: project.updated,
+            latest_version: None,
+            license: project.license.as_ref().and_then(|l| l.id.clone()),
+            gallery: gallery.iter().map(|g| g.url.clone()).collect(),
+            featured_gallery: gallery.iter().find(|g| g.featured).map(|g| g.url.clone()),
+        }
+    }
+
+    /// Sets `latest_version` to the most recently published of `versions`
+    /// belonging to this project.
+    pub fn with_latest_version(mut self, versions: &[Version]) -> Self {
+        self.latest_version = versions
+            .iter()
+            .filter(|v| v.project_id == self.project_id)
+            .max_by_key(|v| v.date_published)
+            .map(|v| v.id.clone());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SearchResults {
+    pub hits: Vec<ProjectHit>,
+    pub offset: u32,
+    pub limit: u32,
+    pub total_hits: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FacetType {
+    ProjectType,
+    Categories,
+    Versions,
+    ClientSide,
+    ServerSide,
+    OpenSource,
+    License,
+    Title,
+    Author,
+    Follows,
+    ProjectId,
+    Downloads,
+    Color,
+    DateCreated,
+    DateModified,
+}
+
+impl FacetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FacetType::ProjectType => "project_type",
+            FacetType::Categories => "categories",
+            FacetType::Versions => "versions",
+            FacetType::ClientSide => "client_side",
+            FacetType::ServerSide => "server_side",
+            FacetType::OpenSource => "open_source",
+            FacetType::License => "license",
+            FacetType::Title => "title",
+            FacetType::Author => "author",
+            FacetType::Follows => "follows",
+            FacetType::ProjectId => "project_id",
+            FacetType::Downloads => "downloads",
+            FacetType::Color => "color",
+            FacetType::DateCreated => "date_created",
+            FacetType::DateModified => "date_modified",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FacetOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl FacetOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FacetOp::Eq => ":",
+            FacetOp::Ne => "!=",
+            FacetOp::Gt => ">",
+            FacetOp::Gte => ">=",
+            FacetOp::Lt => "<",
+            FacetOp::Lte => "<=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facet {
+    pub facet_type: FacetType,
+    pub op: FacetOp,
+    pub value: String,
+}
+
+impl Facet {
+    pub fn new(facet_type: FacetType, op: FacetOp, value: impl Into<String>) -> Self {
+        Facet {
+            facet_type,
+            op,
+            value: value.into(),
+        }
+    }
+
+    pub fn eq(facet_type: FacetType, value: impl Into<String>) -> Self {
+        Facet::new(facet_type, FacetOp::Eq, value)
+    }
+}
+
+impl std::fmt::Display for Facet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.facet_type.as_str(),
+            self.op.as_str(),
+            self.value
+        )
+    }
+}
+
+/// Facet filter for the search endpoint. The outer list is AND-ed, each
+/// inner group is OR-ed, matching upstream's `[["a","b"],["c"]]` syntax.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Facets {
+    pub groups: Vec<Vec<Facet>>,
+}
+
+impl Facets {
+    pub fn new() -> Self {
+        Facets::default()
+    }
+
+    pub fn and(mut self, facet: Facet) -> Self {
+        self.groups.push(vec![facet]);
+        self
+    }
+
+    pub fn and_any(mut self, facets: impl IntoIterator<Item = Facet>) -> Self {
+        let group: Vec<Facet> = facets.into_iter().collect();
+        if !group.is_empty() {
+            self.groups.push(group);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Renders the value of the `facets` query parameter.
+    pub fn to_query(&self) -> String {
+        serde_json::to_string(self).expect("facets serialize to JSON")
+    }
+}
+
+impl Serialize for Facets {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let groups: Vec<Vec<String>> = self
+            .groups
+            .iter()
+            .map(|group| group.iter().map(|facet| facet.to_string()).collect())
+            .collect();
+        groups.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ProjectPermissions(u64);
+
+impl ProjectPermissions {
+    pub const UPLOAD_VERSION: Self = Self(1 << 0);
+    pub const DELETE_VERSION: Self = Self(1 << 1);
+    pub const EDIT_DETAILS: Self = Self(1 << 2);
+    pub const EDIT_BODY: Self = Self(1 << 3);
+    pub const MANAGE_INVITES: Self = Self(1 << 4);
+    pub const REMOVE_MEMBER: Self = Self(1 << 5);
+    pub const EDIT_MEMBER: Self = Self(1 << 6);
+    pub const DELETE_PROJECT: Self = Self(1 << 7);
+    pub const VIEW_ANALYTICS: Self = Self(1 << 8);
+    pub const VIEW_PAYOUTS: Self = Self(1 << 9);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl std::ops::BitOr for ProjectPermissions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ProjectPermissions {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl std::ops::BitAnd for ProjectPermissions {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserInfo {
+    pub id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    #[serde_as(as = "Option<FromChrono04DateTime>")]
+    pub created: Option<DateTime<Utc>>,
+    pub role: Option<String>,
+    pub badges: Option<u64>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct User {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    #[serde_as(as = "Option<FromChrono04DateTime>")]
+    pub created: Option<DateTime<Utc>>,
+    pub role: Option<String>,
+    pub badges: Option<u64>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TeamMember {
+    pub team_id: String,
+    pub user: UserInfo,
+    pub role: String,
+    pub is_owner: Option<bool>,
+    pub permissions: Option<ProjectPermissions>,
+    pub accepted: bool,
+    pub payouts_split: Option<f64>,
+    pub ordering: Option<i64>,
+}
+
+impl TeamMember {
+    /// Upstream v3 reports ownership directly; v2 documents only carry the
+    /// `Owner` role.
+    pub fn is_owner(&self) -> bool {
+        self.is_owner.unwrap_or_else(|| self.role == "Owner")
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Team {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub members: Vec<TeamMember>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl Team {
+    pub fn owner(&self) -> Option<&TeamMember> {
+        self.members.iter().find(|m| m.is_owner())
+    }
+
+    pub fn member(&self, user_id: &str) -> Option<&TeamMember> {
+        self.members.iter().find(|m| m.user.id == user_id)
+    }
+
+    /// Accepted members in display order, owner first on ties.
+    pub fn accepted_members(&self) -> Vec<&TeamMember> {
+        let mut members: Vec<&TeamMember> = self.members.iter().filter(|m| m.accepted).collect();
+        members.sort_by_key(|m| (m.ordering.unwrap_or(i64::MAX), !m.is_owner()));
+        members
+    }
+}
+
+impl Project {
+    /// Returns `None` when `team` is not this project's team.
+    pub fn owner<'a>(&self, team: &'a Team) -> Option<&'a UserInfo> {
+        if team.id != self.team {
+            return None;
+        }
+        team.owner().map(|m| &m.user)
+    }
+
+    pub fn members<'a>(&self, team: &'a Team) -> Vec<&'a TeamMember> {
+        if team.id != self.team {
+            return Vec::new();
+        }
+        team.accepted_members()
+    }
+}
+
+impl Version {
+    pub fn author<'a>(&self, users: &'a [User]) -> Option<&'a User> {
+        users.iter().find(|u| u.id == self.author_id)
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Organization {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub slug: String,
+    pub name: String,
+    pub team_id: String,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub color: Option<u32>,
+    pub members: Option<Vec<TeamMember>>,
+    pub projects: Option<Vec<String>>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl Organization {
+    /// Projects listed by the organization, plus any whose `organization`
+    /// field points back at it.
+    pub fn projects<'a>(&self, projects: &'a [Project]) -> Vec<&'a Project> {
+        let ids = self.projects.as_deref().unwrap_or_default();
+        let mut found = select_projects(ids, projects);
+        for project in projects {
+            if project.organization.as_deref() == Some(self.id.as_str())
+                && !ids.contains(&project.id)
+            {
+                found.push(project);
+            }
+        }
+        found
+    }
+
+    pub fn owner(&self) -> Option<&TeamMember> {
+        self.members.as_ref()?.iter().find(|m| m.is_owner())
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Collection {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub user: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub color: Option<u32>,
+    pub status: Option<String>,
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub created: DateTime<Utc>,
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub updated: DateTime<Utc>,
+    pub projects: Vec<String>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl Collection {
+    pub fn projects<'a>(&self, projects: &'a [Project]) -> Vec<&'a Project> {
+        select_projects(&self.projects, projects)
+    }
+
+    /// Ids in the collection with no matching project in `projects`.
+    pub fn missing_projects(&self, projects: &[Project]) -> Vec<String> {
+        self.projects
+            .iter()
+            .filter(|id| !projects.iter().any(|p| &p.id == *id))
+            .cloned()
+            .collect()
+    }
+}
+
+fn select_projects<'a>(ids: &[String], projects: &'a [Project]) -> Vec<&'a Project> {
+    ids.iter()
+        .filter_map(|id| projects.iter().find(|p| &p.id == id))
+        .collect()
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LicenseTag {
+    pub short: String,
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DonationPlatform {
+    pub short: String,
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReportType {
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProjectType {
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SideType {
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagKind {
+    Category,
+    Loader,
+    License,
+    DonationPlatform,
+    ProjectType,
+    SideType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTag {
+    pub kind: TagKind,
+    pub value: String,
+}
+
+/// All of upstream's tag lists, used to check the free-form strings on a
+/// `Project`. Kinds with no loaded tags are not checked.
+#[derive(Debug, Clone, Default)]
+pub struct TagSet {
+    pub categories: Vec<Category>,
+    pub loaders: Vec<Loader>,
+    pub game_versions: Vec<GameVersion>,
+    pub licenses: Vec<LicenseTag>,
+    pub donation_platforms: Vec<DonationPlatform>,
+    pub report_types: Vec<ReportType>,
+    pub project_types: Vec<ProjectType>,
+    pub side_types: Vec<SideType>,
+}
+
+impl TagSet {
+    pub fn is_category(&self, name: &str, project_type: Option<&str>) -> bool {
+        find_category(&self.categories, name, project_type).is_some()
+    }
+
+    pub fn is_loader(&self, name: &str) -> bool {
+        self.loaders.iter().any(|l| l.name == name)
+    }
+
+    /// Custom licenses use SPDX `LicenseRef-` ids and are never listed.
+    pub fn is_license(&self, id: &str) -> bool {
+        id.starts_with("LicenseRef-") || self.licenses.iter().any(|l| l.short == id)
+    }
+
+    pub fn is_donation_platform(&self, id: &str) -> bool {
+        self.donation_platforms.iter().any(|d| d.short == id)
+    }
+
+    pub fn is_project_type(&self, name: &str) -> bool {
+        self.project_types.iter().any(|t| t.name == name)
+    }
+
+    pub fn is_side_type(&self, name: &str) -> bool {
+        self.side_types.iter().any(|t| t.name == name)
+    }
+
+    /// Loaders that exist but do not support the project's type are
+    /// reported as well.
+    pub fn validate(&self, project: &Project) -> Vec<UnknownTag> {
+        let mut unknown = Vec::new();
+        let mut report = |kind, value: &str| {
+            unknown.push(UnknownTag {
+                kind,
+                value: value.to_string(),
+            })
+        };
+        let project_type = project.project_type.as_deref();
+
+        if !self.categories.is_empty() {
+            let categories = project.categories.iter().flatten();
+            let additional = project.additional_categories.iter().flatten();
+            for name in categories.chain(additional) {
+                if !self.is_category(name, project_type) {
+                    report(TagKind::Category, name);
+                }
+            }
+        }
+        if !self.loaders.is_empty() {
+            for name in unsupported_loaders(&self.loaders, project) {
+                report(TagKind::Loader, name);
+            }
+        }
+        if !self.licenses.is_empty() {
+            let license = project.license.as_ref().and_then(|l| l.id.as_deref());
+            for id in license.into_iter().filter(|id| !self.is_license(id)) {
+                report(TagKind::License, id);
+            }
+        }
+        if !self.donation_platforms.is_empty() {
+            let donations = project.donation_urls.iter().flatten();
+            for id in donations.filter_map(|d| d.id.as_deref()) {
+                if !self.is_donation_platform(id) {
+                    report(TagKind::DonationPlatform, id);
+                }
+            }
+        }
+        if !self.project_types.is_empty() {
+            for name in project_type
+                .into_iter()
+                .filter(|t| !self.is_project_type(t))
+            {
+                report(TagKind::ProjectType, name);
+            }
+        }
+        if !self.side_types.is_empty() {
+            let sides = [
+                project.client_side.as_deref(),
+                project.server_side.as_deref(),
+            ];
+            for name in sides.into_iter().flatten() {
+                if !self.is_side_type(name) {
+                    report(TagKind::SideType, name);
+                }
+            }
+        }
+        unknown
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    Sha1,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    pub fn hex_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha1 => 40,
+            HashAlgorithm::Sha512 => 128,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashError {
+    InvalidLength(usize),
+    InvalidCharacter(char),
+    AlgorithmMismatch {
+        expected: HashAlgorithm,
+        found: HashAlgorithm,
+    },
+}
+
+impl std::fmt::Display for HashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashError::InvalidLength(len) => write!(f, "invalid digest length {len}"),
+            HashError::InvalidCharacter(c) => write!(f, "invalid hex character {c:?}"),
+            HashError::AlgorithmMismatch { expected, found } => {
+                write!(f, "expected a {expected:?} digest, found {found:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HashError {}
+
+/// A lowercase hex SHA-1 or SHA-512 digest; the algorithm follows from the
+/// length.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileHash(String);
+
+impl FileHash {
+    pub fn parse(value: &str) -> Result<Self, HashError> {
+        if value.len() != 40 && value.len() != 128 {
+            return Err(HashError::InvalidLength(value.len()));
+        }
+        if let Some(c) = value.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(HashError::InvalidCharacter(c));
+        }
+        Ok(FileHash(value.to_ascii_lowercase()))
+    }
+
+    pub fn parse_as(value: &str, algorithm: HashAlgorithm) -> Result<Self, HashError> {
+        let hash = FileHash::parse(value)?;
+        if hash.algorithm() != algorithm {
+            return Err(HashError::AlgorithmMismatch {
+                expected: algorithm,
+                found: hash.algorithm(),
+            });
+        }
+        Ok(hash)
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        if self.0.len() == 40 {
+            HashAlgorithm::Sha1
+        } else {
+            HashAlgorithm::Sha512
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for FileHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::str::FromStr for FileHash {
+    type Err = HashError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FileHash::parse(s)
+    }
+}
+
+impl Serialize for FileHash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileHash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        FileHash::parse(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Hashes {
+    pub fn get(&self, algorithm: HashAlgorithm) -> &str {
+        match algorithm {
+            HashAlgorithm::Sha1 => &self.sha1,
+            HashAlgorithm::Sha512 => &self.sha512,
+        }
+    }
+
+    pub fn matches(&self, hash: &FileHash) -> bool {
+        self.get(hash.algorithm())
+            .eq_ignore_ascii_case(hash.as_str())
+    }
+
+    pub fn file_hash(&self, algorithm: HashAlgorithm) -> Result<FileHash, HashError> {
+        FileHash::parse_as(self.get(algorithm), algorithm)
+    }
+}
+
+impl File {
+    pub fn file_hash(&self, algorithm: HashAlgorithm) -> Result<FileHash, HashError> {
+        self.hashes.file_hash(algorithm)
+    }
+}
+
+impl FileInfo {
+    pub fn file_hash(&self, algorithm: HashAlgorithm) -> Result<FileHash, HashError> {
+        self.hashes.file_hash(algorithm)
+    }
+}
+
+impl Version {
+    pub fn file_by_hash(&self, hash: &FileHash) -> Option<&FileInfo> {
+        self.files.iter().find(|f| f.hashes.matches(hash))
+    }
+
+    pub fn primary_file(&self) -> Option<&FileInfo> {
+        self.files
+            .iter()
+            .find(|f| f.primary)
+            .or_else(|| self.files.first())
+    }
+}
+
+/// Body of `POST /version_files`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HashesRequest {
+    pub hashes: Vec<FileHash>,
+    pub algorithm: HashAlgorithm,
+}
+
+impl HashesRequest {
+    pub fn from_files<'a>(
+        files: impl IntoIterator<Item = &'a File>,
+        algorithm: HashAlgorithm,
+    ) -> Result<Self, HashError> {
+        let hashes = files
+            .into_iter()
+            .map(|f| f.file_hash(algorithm))
+            .collect::<Result<_, _>>()?;
+        Ok(HashesRequest { hashes, algorithm })
+    }
+
+    pub fn from_version(version: &Version, algorithm: HashAlgorithm) -> Result<Self, HashError> {
+        let hashes = version
+            .files
+            .iter()
+            .map(|f| f.file_hash(algorithm))
+            .collect::<Result<_, _>>()?;
+        Ok(HashesRequest { hashes, algorithm })
+    }
+
+    /// Answers the request from stored versions. Hashes with no matching
+    /// version, or of a different algorithm, are left out as upstream does.
+    pub fn resolve(&self, versions: &[Version]) -> VersionsByHash {
+        self.hashes
+            .iter()
+            .filter(|hash| hash.algorithm() == self.algorithm)
+            .filter_map(|hash| {
+                let version = versions.iter().find(|v| v.file_by_hash(hash).is_some())?;
+                Some((hash.clone(), version.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Body of `POST /version_file/{hash}/update`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct UpdateRequest {
+    pub loaders: Option<Vec<String>>,
+    pub game_versions: Option<Vec<String>>,
+}
+
+impl UpdateRequest {
+    pub fn accepts(&self, version: &Version) -> bool {
+        fn overlaps(wanted: &Option<Vec<String>>, offered: &Option<Vec<String>>) -> bool {
+            match wanted {
+                Some(wanted) if !wanted.is_empty() => offered
+                    .iter()
+                    .flatten()
+                    .any(|o| wanted.iter().any(|w| w == o)),
+                _ => true,
+            }
+        }
+        overlaps(&self.loaders, &version.loaders)
+            && overlaps(&self.game_versions, &version.game_versions)
+    }
+
+    /// Newest version of `project_id` matching the filters.
+    pub fn latest<'a>(&self, project_id: &str, versions: &'a [Version]) -> Option<&'a Version> {
+        versions
+            .iter()
+            .filter(|v| v.project_id == project_id && self.accepts(v))
+            .max_by_key(|v| v.date_published)
+    }
+}
+
+/// Body of `POST /version_files/update`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HashesUpdateRequest {
+    pub hashes: Vec<FileHash>,
+    pub algorithm: HashAlgorithm,
+    pub loaders: Option<Vec<String>>,
+    pub game_versions: Option<Vec<String>>,
+}
+
+impl HashesUpdateRequest {
+    pub fn filters(&self) -> UpdateRequest {
+        UpdateRequest {
+            loaders: self.loaders.clone(),
+            game_versions: self.game_versions.clone(),
+        }
+    }
+
+    /// Maps every known hash to the newest matching version of its project.
+    pub fn resolve(&self, versions: &[Version]) -> VersionsByHash {
+        let filters = self.filters();
+        self.hashes
+            .iter()
+            .filter(|hash| hash.algorithm() == self.algorithm)
+            .filter_map(|hash| {
+                let current = versions.iter().find(|v| v.file_by_hash(hash).is_some())?;
+                let latest = filters.latest(&current.project_id, versions)?;
+                Some((hash.clone(), latest.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Response of the `version_files` endpoints.
+pub type VersionsByHash = std::collections::HashMap<FileHash, Version>;
+
+/// Response of `GET /project/{id}/dependencies`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct ProjectDependencies {
+    pub projects: Vec<Project>,
+    pub versions: Vec<Version>,
+}
+
+impl ProjectDependencies {
+    /// Project ids and version ids referenced by the dependencies of
+    /// `project`'s versions, deduplicated in first-seen order. Useful for
+    /// fetching only the documents `compute` needs.
+    pub fn referenced_ids(project: &Project, versions: &[Version]) -> (Vec<String>, Vec<String>) {
+        let mut project_ids: Vec<String> = Vec::new();
+        let mut version_ids: Vec<String> = Vec::new();
+        let dependencies = versions
+            .iter()
+            .filter(|v| v.project_id == project.id)
+            .flat_map(|v| v.dependencies.iter().flatten());
+        for dependency in dependencies {
+            if let Some(version_id) = &dependency.version_id {
+                if !version_ids.contains(version_id) {
+                    version_ids.push(version_id.clone());
+                }
+            } else if let Some(project_id) = &dependency.project_id
+                && project_id != &project.id
+                && !project_ids.contains(project_id)
+            {
+                project_ids.push(project_id.clone());
+            }
+        }
+        (project_ids, version_ids)
+    }
+
+    /// Computes the listing from stored documents. Referenced ids missing
+    /// from `versions` or `projects` are skipped.
+    pub fn compute(project: &Project, versions: &[Version], projects: &[Project]) -> Self {
+        let (project_ids, version_ids) = Self::referenced_ids(project, versions);
+        ProjectDependencies {
+            projects: project_ids
+                .iter()
+                .filter_map(|id| projects.iter().find(|p| &p.id == id).cloned())
+                .collect(),
+            versions: version_ids
+                .iter()
+                .filter_map(|id| versions.iter().find(|v| &v.id == id).cloned())
+                .collect(),
+        }
+    }
+}
+
+/// What a standalone `File` record knows beyond its `FileInfo`.
+#[derive(Debug, Clone)]
+pub struct FileContext {
+    pub version_id: String,
+    pub project_id: String,
+    pub file_cdn_cached: Option<bool>,
+    pub sync_at: DateTime<Utc>,
+}
+
+impl File {
+    pub fn from_info(info: FileInfo, ctx: FileContext) -> Self {
+        let FileInfo {
+            hashes,
+            url,
+            filename,
+            primary,
+            size,
+            file_type,
+        } = info;
+        let FileContext {
+            version_id,
+            project_id,
+            file_cdn_cached,
+            sync_at,
+        } = ctx;
+        File {
+            hashes,
+            url,
+            filename,
+            primary,
+            size,
+            file_type,
+            version_id,
+            project_id,
+            file_cdn_cached,
+            sync_at,
+        }
+    }
+
+    pub fn info(&self) -> FileInfo {
+        FileInfo::from(self.clone())
+    }
+
+    pub fn context(&self) -> FileContext {
+        FileContext {
+            version_id: self.version_id.clone(),
+            project_id: self.project_id.clone(),
+            file_cdn_cached: self.file_cdn_cached,
+            sync_at: self.sync_at,
+        }
+    }
+}
+
+impl From<File> for FileInfo {
+    fn from(file: File) -> Self {
+        let File {
+            hashes,
+            url,
+            filename,
+            primary,
+            size,
+            file_type,
+            version_id: _,
+            project_id: _,
+            file_cdn_cached: _,
+            sync_at: _,
+        } = file;
+        FileInfo {
+            hashes,
+            url,
+            filename,
+            primary,
+            size,
+            file_type,
+        }
+    }
+}
+
+impl Version {
+    /// Standalone file records for every file of this version.
+    pub fn to_files(&self) -> Vec<File> {
+        self.files
+            .iter()
+            .map(|info| {
+                File::from_info(
+                    info.clone(),
+                    FileContext {
+                        version_id: self.id.clone(),
+                        project_id: self.project_id.clone(),
+                        file_cdn_cached: None,
+                        sync_at: self.sync_at,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+/// Categories and loaders grouped the way Modrinth's UI shows them: by
+/// `header` ("categories", "features", "resolutions", "performance
+/// impact"), per project type.
+#[derive(Debug, Clone, Default)]
+pub struct CategoryCatalog {
+    categories: Vec<Category>,
+    loaders: Vec<Loader>,
+}
+
+/// One header's worth of a project's categories.
+#[derive(Debug, Clone)]
+pub struct CategoryGroup<'a> {
+    pub header: &'a str,
+    /// From `Project.categories`.
+    pub categories: Vec<&'a Category>,
+    /// From `Project.additional_categories`.
+    pub additional: Vec<&'a Category>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GroupedCategories<'a> {
+    /// In catalog header order.
+    pub groups: Vec<CategoryGroup<'a>>,
+    /// Names not in the catalog for the project's type.
+    pub unknown: Vec<String>,
+}
+
+impl CategoryCatalog {
+    pub fn new(categories: Vec<Category>, loaders: Vec<Loader>) -> Self {
+        CategoryCatalog {
+            categories,
+            loaders,
+        }
+    }
+
+    /// Headers in the order they first appear.
+    pub fn headers(&self) -> Vec<&str> {
+        let mut headers: Vec<&str> = Vec::new();
+        for c in &self.categories {
+            if !headers.contains(&c.header.as_str()) {
+                headers.push(&c.header);
+            }
+        }
+        headers
+    }
+
+    /// Categories valid for `project_type`; ones without a type apply to all.
+    pub fn for_project_type(&self, project_type: &str) -> Vec<&Category> {
+        self.categories
+            .iter()
+            .filter(|c| c.project_type.as_deref().is_none_or(|t| t == project_type))
+            .collect()
+    }
+
+    pub fn for_header(&self, header: &str, project_type: &str) -> Vec<&Category> {
+        self.for_project_type(project_type)
+            .into_iter()
+            .filter(|c| c.header == header)
+            .collect()
+    }
+
+    pub fn get(&self, name: &str, project_type: Option<&str>) -> Option<&Category> {
+        find_category(&self.categories, name, project_type)
+    }
+
+    pub fn loader(&self, name: &str) -> Option<&Loader> {
+        self.loaders.iter().find(|l| l.name == name)
+    }
+
+    pub fn loaders_for(&self, project_type: &str) -> Vec<&Loader> {
+        self.loaders
+            .iter()
+            .filter(|l| l.supported_project_types.iter().any(|t| t == project_type))
+            .collect()
+    }
+
+    pub fn group(&self, project: &Project) -> GroupedCategories<'_> {
+        let project_type = project.project_type.as_deref();
+        let mut grouped = GroupedCategories::default();
+        let mut groups: Vec<CategoryGroup> = self
+            .headers()
+            .into_iter()
+            .map(|header| CategoryGroup {
+                header,
+                categories: Vec::new(),
+                additional: Vec::new(),
+            })
+            .collect();
+
+        let primary = project.categories.iter().flatten().map(|n| (n, false));
+        let additional = project
+            .additional_categories
+            .iter()
+            .flatten()
+            .map(|n| (n, true));
+        for (name, is_additional) in primary.chain(additional) {
+            let Some(category) = self.get(name, project_type) else {
+                grouped.unknown.push(name.clone());
+                continue;
+            };
+            if let Some(group) = groups.iter_mut().find(|g| g.header == category.header) {
+                if is_additional {
+                    group.additional.push(category);
+                } else {
+                    group.categories.push(category);
+                }
+            }
+        }
+
+        grouped.groups = groups
+            .into_iter()
+            .filter(|g| !g.categories.is_empty() || !g.additional.is_empty())
+            .collect();
+        grouped
+    }
+
+    /// Loaders of `project` that are unknown or, when the project has a
+    /// type, do not support it.
+    pub fn unsupported_loaders(&self, project: &Project) -> Vec<String> {
+        unsupported_loaders(&self.loaders, project)
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// A category matches when the names agree and, if both it and the lookup
+/// have a project type, so do the types.
+fn find_category<'a>(
+    categories: &'a [Category],
+    name: &str,
+    project_type: Option<&str>,
+) -> Option<&'a Category> {
+    categories.iter().find(|c| {
+        c.name == name
+            && match (c.project_type.as_deref(), project_type) {
+                (Some(a), Some(b)) => a == b,
+                _ => true,
+            }
+    })
+}
+
+fn unsupported_loaders<'a>(loaders: &[Loader], project: &'a Project) -> Vec<&'a str> {
+    let project_type = project.project_type.as_deref();
+    project
+        .loaders
+        .iter()
+        .flatten()
+        .filter(|name| match loaders.iter().find(|l| l.name == **name) {
+            None => true,
+            Some(l) => {
+                project_type.is_some_and(|t| !l.supported_project_types.iter().any(|s| s == t))
+            }
+        })
+        .map(String::as_str)
+        .collect()
+}
+
+impl TagSet {
+    pub fn category_catalog(&self) -> CategoryCatalog {
+        CategoryCatalog::new(self.categories.clone(), self.loaders.clone())
+    }
 }
 
 #[cfg(test)]
@@ -332,6 +1573,15 @@ mod tests {
         let p: Project = serde_json::from_str(json).expect("deserialize project from json");
         assert_eq!(p.id, "Ua7DFN59");
         assert_eq!(p.slug, "yungs-api");
+        assert!(p.moderator_message.is_none());
+        assert!(p.translated_description.is_none());
+        assert!(p.organization.is_none());
+        let license = p.license.expect("project license");
+        assert!(license.url.is_none());
+        assert_eq!(
+            license.effective_url().as_deref(),
+            Some("https://spdx.org/licenses/LGPL-3.0-only.html")
+        );
     }
 
     #[test]
@@ -391,6 +1641,8 @@ mod tests {
         let v: Version = serde_json::from_str(json).expect("deserialize version from json");
         assert_eq!(v.id, "1ZHtT6Xo");
         assert_eq!(v.project_id, "Wnxd13zP");
+        assert_eq!(v.slug.as_deref(), Some("clumps"));
+        assert!(v.ordering.is_none());
     }
 
     #[test]
@@ -485,4 +1737,853 @@ mod tests {
         let gv: GameVersion = serde_json::from_str(json).expect("deserialize game version from json");
         assert_eq!(gv.version, "25w04a");
     }
+
+    #[test]
+    fn test_modrinth_search_results_model() {
+        let json = r###"
+        {
+            "hits": [
+                {
+                    "project_id": "P7dR8mSH",
+                    "project_type": "mod",
+                    "slug": "fabric-api",
+                    "author": "modmuss50",
+                    "title": "Fabric API",
+                    "description": "Lightweight and modular API providing common hooks and intercompatibility measures utilized by mods using the Fabric toolchain.",
+                    "categories": [
+                        "fabric",
+                        "library"
+                    ],
+                    "display_categories": [
+                        "fabric",
+                        "library"
+                    ],
+                    "versions": [
+                        "1.20.1",
+                        "1.21.4"
+                    ],
+                    "downloads": 98765432,
+                    "follows": 23456,
+                    "icon_url": "https://cdn.modrinth.com/data/P7dR8mSH/icon.png",
+                    "date_created": "2021-04-29T16:54:28.046512Z",
+                    "date_modified": "2025-01-20T18:09:07.226417Z",
+                    "latest_version": "RCtNdpPr",
+                    "license": "Apache-2.0",
+                    "client_side": "optional",
+                    "server_side": "optional",
+                    "gallery": [],
+                    "featured_gallery": null,
+                    "color": 14407054
+                }
+            ],
+            "offset": 0,
+            "limit": 10,
+            "total_hits": 1
+        }
+        "###;
+
+        let r: SearchResults =
+            serde_json::from_str(json).expect("deserialize search results from json");
+        assert_eq!(r.total_hits, 1);
+        assert_eq!(r.hits[0].project_id, "P7dR8mSH");
+        assert_eq!(r.hits[0].latest_version.as_deref(), Some("RCtNdpPr"));
+    }
+
+    #[test]
+    fn test_modrinth_project_hit_from_project() {
+        let json = r###"
+        {
+            "_id": "Ua7DFN59",
+            "slug": "yungs-api",
+            "title": "YUNG's API",
+            "categories": ["library", "worldgen"],
+            "additional_categories": ["utility"],
+            "loaders": ["fabric", "forge"],
+            "game_versions": ["1.20.1", "1.21.1"],
+            "versions": ["xET3UZBe", "UNVzqGkX"],
+            "license": { "id": "LGPL-3.0-only", "name": null, "url": null },
+            "gallery": [
+                {
+                    "url": "https://cdn.modrinth.com/a.png",
+                    "featured": false,
+                    "created": { "$date": "2022-11-28T17:17:12.676Z" }
+                },
+                {
+                    "url": "https://cdn.modrinth.com/b.png",
+                    "featured": true,
+                    "created": { "$date": "2022-11-28T17:17:12.676Z" }
+                }
+            ],
+            "downloads": 9979426,
+            "followers": 1773,
+            "team": "3TidTIHz",
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+
+        let p: Project = serde_json::from_str(json).expect("deserialize project from json");
+        let hit = ProjectHit::from_project(&p, "YUNGNICKYOUNG");
+        assert_eq!(hit.project_id, "Ua7DFN59");
+        assert_eq!(hit.author, "YUNGNICKYOUNG");
+        assert_eq!(
+            hit.display_categories,
+            ["library", "worldgen", "fabric", "forge"]
+        );
+        assert_eq!(
+            hit.categories,
+            ["library", "worldgen", "utility", "fabric", "forge"]
+        );
+        assert_eq!(hit.versions, ["1.20.1", "1.21.1"]);
+        assert_eq!(hit.latest_version, None);
+
+        let versions = r###"
+        [
+            {
+                "_id": "xET3UZBe",
+                "project_id": "Ua7DFN59",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2025-03-01T00:00:00Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+            },
+            {
+                "_id": "UNVzqGkX",
+                "project_id": "Ua7DFN59",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-01-01T00:00:00Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+            }
+        ]
+        "###;
+        let versions: Vec<Version> =
+            serde_json::from_str(versions).expect("deserialize versions from json");
+        let hit = hit.with_latest_version(&versions);
+        assert_eq!(hit.latest_version.as_deref(), Some("xET3UZBe"));
+        assert_eq!(hit.license.as_deref(), Some("LGPL-3.0-only"));
+        assert_eq!(
+            hit.featured_gallery.as_deref(),
+            Some("https://cdn.modrinth.com/b.png")
+        );
+        assert_eq!(hit.date_created, p.published);
+    }
+
+    #[test]
+    fn test_modrinth_facets_query() {
+        let facets = Facets::new()
+            .and(Facet::eq(FacetType::ProjectType, "mod"))
+            .and_any([
+                Facet::eq(FacetType::Categories, "forge"),
+                Facet::eq(FacetType::Categories, "neoforge"),
+            ])
+            .and(Facet::eq(FacetType::Versions, "1.20.1"))
+            .and(Facet::new(FacetType::Downloads, FacetOp::Gte, "1000"));
+
+        assert_eq!(
+            facets.to_query(),
+            r#"[["project_type:mod"],["categories:forge","categories:neoforge"],["versions:1.20.1"],["downloads>=1000"]]"#
+        );
+        assert_eq!(serde_json::to_string(&facets).unwrap(), facets.to_query());
+        assert_eq!(Facets::new().to_query(), "[]");
+    }
+
+    #[test]
+    fn test_modrinth_user_model() {
+        let json = r###"
+        {
+            "_id": "l45nT5ov",
+            "username": "Jaredlll08",
+            "name": null,
+            "avatar_url": "https://cdn.modrinth.com/user/l45nT5ov/avatar.png",
+            "bio": "Modder",
+            "created": {
+                "$date": "2021-01-10T21:20:28.385Z"
+            },
+            "role": "developer",
+            "badges": 0,
+            "sync_at": {
+                "$date": "2025-01-26T09:11:24.542Z"
+            }
+        }
+        "###;
+
+        let u: User = serde_json::from_str(json).expect("deserialize user from json");
+        assert_eq!(u.id, "l45nT5ov");
+        assert_eq!(u.username, "Jaredlll08");
+    }
+
+    #[test]
+    fn test_modrinth_team_model() {
+        let json = r###"
+        {
+            "_id": "3TidTIHz",
+            "members": [
+                {
+                    "team_id": "3TidTIHz",
+                    "user": {
+                        "id": "Zc4zBcHa",
+                        "username": "Helper",
+                        "avatar_url": null,
+                        "bio": null,
+                        "created": { "$date": "2022-01-01T00:00:00Z" },
+                        "role": "developer"
+                    },
+                    "role": "Contributor",
+                    "permissions": 3,
+                    "accepted": true,
+                    "payouts_split": 0.0,
+                    "ordering": 1
+                },
+                {
+                    "team_id": "3TidTIHz",
+                    "user": {
+                        "id": "KR4hBMaR",
+                        "username": "YUNGNICKYOUNG",
+                        "avatar_url": "https://cdn.modrinth.com/user/KR4hBMaR/avatar.png",
+                        "bio": null,
+                        "created": { "$date": "2020-12-06T01:24:51.384Z" },
+                        "role": "developer"
+                    },
+                    "role": "Owner",
+                    "permissions": 1023,
+                    "accepted": true,
+                    "payouts_split": 100.0,
+                    "ordering": 0
+                },
+                {
+                    "team_id": "3TidTIHz",
+                    "user": {
+                        "id": "aaaaaaaa",
+                        "username": "Invited",
+                        "created": null,
+                        "role": "developer"
+                    },
+                    "role": "Member",
+                    "permissions": null,
+                    "accepted": false,
+                    "payouts_split": null,
+                    "ordering": 2
+                }
+            ],
+            "sync_at": {
+                "$date": "2025-06-17T12:44:55.245Z"
+            }
+        }
+        "###;
+
+        let t: Team = serde_json::from_str(json).expect("deserialize team from json");
+        assert_eq!(t.id, "3TidTIHz");
+
+        let owner = t.owner().expect("team has an owner");
+        assert_eq!(owner.user.username, "YUNGNICKYOUNG");
+        let permissions = owner.permissions.expect("owner permissions");
+        assert!(
+            permissions
+                .contains(ProjectPermissions::DELETE_PROJECT | ProjectPermissions::VIEW_PAYOUTS)
+        );
+
+        let contributor = t.member("Zc4zBcHa").expect("contributor");
+        let permissions = contributor.permissions.expect("contributor permissions");
+        assert!(permissions.contains(ProjectPermissions::UPLOAD_VERSION));
+        assert!(!permissions.contains(ProjectPermissions::EDIT_DETAILS));
+
+        let names: Vec<&str> = t
+            .accepted_members()
+            .iter()
+            .map(|m| m.user.username.as_str())
+            .collect();
+        assert_eq!(names, ["YUNGNICKYOUNG", "Helper"]);
+    }
+
+    #[test]
+    fn test_modrinth_organization_model() {
+        let json = r###"
+        {
+            "_id": "4reLOAKe",
+            "slug": "yung-gang",
+            "name": "YUNG GANG",
+            "team_id": "9zLxbp3P",
+            "description": "YUNG's mods",
+            "icon_url": null,
+            "color": 5970187,
+            "members": [],
+            "projects": ["Ua7DFN59", "missing1"],
+            "sync_at": {
+                "$date": "2025-06-17T12:44:55.245Z"
+            }
+        }
+        "###;
+
+        let o: Organization =
+            serde_json::from_str(json).expect("deserialize organization from json");
+        assert_eq!(o.slug, "yung-gang");
+        assert!(o.owner().is_none());
+
+        let project = r###"
+        {
+            "_id": "Ua7DFN59",
+            "slug": "yungs-api",
+            "team": "3TidTIHz",
+            "organization": "4reLOAKe",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+        let mut other: Project = serde_json::from_str(project).expect("deserialize project");
+        other.id = "AAAAAAAA".to_string();
+        let p: Project = serde_json::from_str(project).expect("deserialize project");
+        let projects = vec![p, other];
+
+        let ids: Vec<&str> = o
+            .projects(&projects)
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(ids, ["Ua7DFN59", "AAAAAAAA"]);
+    }
+
+    #[test]
+    fn test_modrinth_collection_model() {
+        let json = r###"
+        {
+            "_id": "wLnX9Nvu",
+            "user": "KR4hBMaR",
+            "name": "Worldgen",
+            "description": null,
+            "icon_url": null,
+            "color": null,
+            "status": "listed",
+            "created": { "$date": "2024-03-01T10:00:00Z" },
+            "updated": { "$date": "2024-03-02T10:00:00Z" },
+            "projects": ["Ua7DFN59", "P7dR8mSH"],
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+
+        let c: Collection = serde_json::from_str(json).expect("deserialize collection from json");
+        assert_eq!(c.projects.len(), 2);
+        assert_eq!(c.status.as_deref(), Some("listed"));
+        assert!(c.projects(&[]).is_empty());
+        assert_eq!(c.missing_projects(&[]), ["Ua7DFN59", "P7dR8mSH"]);
+    }
+
+    #[test]
+    fn test_modrinth_tag_models() {
+        let license = r###"
+            {
+                "_id": { "$oid": "67960189a71e867c27236cd0" },
+                "short": "LGPL-3.0-only",
+                "name": "GNU Lesser General Public License v3.0 only",
+                "sync_at": { "$date": "2025-01-26T09:34:01.552Z" }
+            }
+        "###;
+        let l: LicenseTag = serde_json::from_str(license).expect("deserialize license from json");
+        assert_eq!(l.short, "LGPL-3.0-only");
+
+        let donation = r###"
+            {
+                "_id": { "$oid": "67960189a71e867c27236cd1" },
+                "short": "patreon",
+                "name": "Patreon",
+                "sync_at": { "$date": "2025-01-26T09:34:01.552Z" }
+            }
+        "###;
+        let d: DonationPlatform =
+            serde_json::from_str(donation).expect("deserialize donation platform from json");
+        assert_eq!(d.short, "patreon");
+
+        let side = r###"
+            {
+                "_id": { "$oid": "67960189a71e867c27236cd2" },
+                "name": "required",
+                "sync_at": { "$date": "2025-01-26T09:34:01.552Z" }
+            }
+        "###;
+        let s: SideType = serde_json::from_str(side).expect("deserialize side type from json");
+        assert_eq!(s.name, "required");
+    }
+
+    #[test]
+    fn test_modrinth_tag_set_validate() {
+        let sync_at = Utc::now();
+        let category = |name: &str| Category {
+            icon: String::new(),
+            name: name.to_string(),
+            project_type: Some("mod".to_string()),
+            header: "categories".to_string(),
+            sync_at,
+        };
+        let tags = TagSet {
+            categories: vec![category("library"), category("worldgen")],
+            loaders: vec![Loader {
+                icon: String::new(),
+                name: "fabric".to_string(),
+                supported_project_types: vec!["mod".to_string()],
+                sync_at,
+            }],
+            licenses: vec![LicenseTag {
+                short: "MIT".to_string(),
+                name: "MIT License".to_string(),
+                sync_at,
+            }],
+            donation_platforms: vec![DonationPlatform {
+                short: "patreon".to_string(),
+                name: "Patreon".to_string(),
+                sync_at,
+            }],
+            ..TagSet::default()
+        };
+
+        let json = r###"
+        {
+            "_id": "Ua7DFN59",
+            "slug": "yungs-api",
+            "project_type": "mod",
+            "categories": ["library", "worldgen"],
+            "additional_categories": ["magic"],
+            "loaders": ["fabric", "quilt"],
+            "license": { "id": "LGPL-3.0-only", "name": null, "url": null },
+            "donation_urls": [
+                { "id": "patreon", "platform": "Patreon", "url": "https://www.patreon.com/yungnickyoung" },
+                { "id": "bmac", "platform": "Buy Me a Coffee", "url": "https://buymeacoffee.com/x" }
+            ],
+            "client_side": "nonsense",
+            "team": "3TidTIHz",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+        let p: Project = serde_json::from_str(json).expect("deserialize project from json");
+
+        let unknown = tags.validate(&p);
+        let unknown: Vec<(TagKind, &str)> =
+            unknown.iter().map(|u| (u.kind, u.value.as_str())).collect();
+        assert_eq!(
+            unknown,
+            [
+                (TagKind::Category, "magic"),
+                (TagKind::Loader, "quilt"),
+                (TagKind::License, "LGPL-3.0-only"),
+                (TagKind::DonationPlatform, "bmac"),
+            ]
+        );
+        assert!(tags.is_license("LicenseRef-All-Rights-Reserved"));
+    }
+
+    #[test]
+    fn test_modrinth_file_hash() {
+        let sha1 = FileHash::parse("D1F522452CFA1286349525CCACE065A8EC7EB940").expect("valid sha1");
+        assert_eq!(sha1.algorithm(), HashAlgorithm::Sha1);
+        assert_eq!(sha1.as_str(), "d1f522452cfa1286349525ccace065a8ec7eb940");
+
+        assert_eq!(FileHash::parse("abc"), Err(HashError::InvalidLength(3)));
+        assert_eq!(
+            FileHash::parse(&"g".repeat(40)),
+            Err(HashError::InvalidCharacter('g'))
+        );
+        assert!(matches!(
+            FileHash::parse_as(sha1.as_str(), HashAlgorithm::Sha512),
+            Err(HashError::AlgorithmMismatch { .. })
+        ));
+        assert!(serde_json::from_str::<FileHash>(r#""not a hash""#).is_err());
+    }
+
+    #[test]
+    fn test_modrinth_version_files_request() {
+        let json = r###"
+        {
+            "hashes": ["d1f522452cfa1286349525ccace065a8ec7eb940", "0000000000000000000000000000000000000000"],
+            "algorithm": "sha1"
+        }
+        "###;
+        let req: HashesRequest = serde_json::from_str(json).expect("deserialize request from json");
+        assert_eq!(req.algorithm, HashAlgorithm::Sha1);
+
+        let versions = r###"
+        [
+            {
+                "_id": "1ZHtT6Xo",
+                "project_id": "Wnxd13zP",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "game_versions": ["1.21.4"],
+                "loaders": ["fabric"],
+                "files": [
+                    {
+                        "hashes": {
+                            "sha512": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                            "sha1": "d1f522452cfa1286349525ccace065a8ec7eb940"
+                        },
+                        "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/1ZHtT6Xo/Clumps-fabric-1.21.4.jar",
+                        "filename": "Clumps-fabric-1.21.4.jar",
+                        "primary": true,
+                        "size": 20721,
+                        "file_type": null
+                    }
+                ],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "NEWER001",
+                "project_id": "Wnxd13zP",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2025-01-22T23:43:33Z" },
+                "downloads": 0,
+                "game_versions": ["1.21.4"],
+                "loaders": ["fabric"],
+                "files": [
+                    {
+                        "hashes": {
+                            "sha512": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                            "sha1": "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+                        },
+                        "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/NEWER001/Clumps-fabric-1.21.4.jar",
+                        "filename": "Clumps-fabric-1.21.4.jar",
+                        "primary": true,
+                        "size": 20721,
+                        "file_type": null
+                    }
+                ],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "OTHERMC1",
+                "project_id": "Wnxd13zP",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2025-02-22T23:43:33Z" },
+                "downloads": 0,
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "files": [
+                    {
+                        "hashes": {
+                            "sha512": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                            "sha1": "cccccccccccccccccccccccccccccccccccccccc"
+                        },
+                        "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/OTHERMC1/Clumps-fabric-1.20.1.jar",
+                        "filename": "Clumps-fabric-1.20.1.jar",
+                        "primary": true,
+                        "size": 20721,
+                        "file_type": null
+                    }
+                ],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            }
+        ]
+        "###;
+        let versions: Vec<Version> =
+            serde_json::from_str(versions).expect("deserialize versions from json");
+
+        let found = req.resolve(&versions);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[&req.hashes[0]].id, "1ZHtT6Xo");
+        let body = serde_json::to_value(&found).expect("serialize response");
+        assert!(
+            body.get("d1f522452cfa1286349525ccace065a8ec7eb940")
+                .is_some()
+        );
+
+        let update = HashesUpdateRequest {
+            hashes: req.hashes.clone(),
+            algorithm: HashAlgorithm::Sha1,
+            loaders: Some(vec!["fabric".to_string()]),
+            game_versions: Some(vec!["1.21.4".to_string()]),
+        };
+        let updates = update.resolve(&versions);
+        assert_eq!(updates[&req.hashes[0]].id, "NEWER001");
+
+        let again = HashesRequest::from_version(&versions[0], HashAlgorithm::Sha512)
+            .expect("stored hashes are valid");
+        assert_eq!(again.hashes[0].algorithm(), HashAlgorithm::Sha512);
+    }
+
+    #[test]
+    fn test_modrinth_project_dependencies() {
+        let projects = r###"
+        [
+            {
+                "_id": "Wnxd13zP",
+                "slug": "Wnxd13zP",
+                "team": "3TidTIHz",
+                "followers": 0,
+                "published": { "$date": "2022-11-28T17:17:12.676Z" },
+                "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+                "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+            },
+            {
+                "_id": "P7dR8mSH",
+                "slug": "P7dR8mSH",
+                "team": "3TidTIHz",
+                "followers": 0,
+                "published": { "$date": "2022-11-28T17:17:12.676Z" },
+                "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+                "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+            }
+        ]
+        "###;
+        let versions = r###"
+        [
+            {
+                "_id": "1ZHtT6Xo",
+                "project_id": "Wnxd13zP",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "dependencies": [
+                    { "project_id": "P7dR8mSH", "dependency_type": "required" },
+                    { "project_id": "Wnxd13zP", "dependency_type": "optional" }
+                ],
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "2ZHtT6Xo",
+                "project_id": "Wnxd13zP",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "dependencies": [
+                    { "project_id": "P7dR8mSH", "dependency_type": "required" },
+                    { "version_id": "FAPI0001", "project_id": "P7dR8mSH", "dependency_type": "required" },
+                    { "project_id": "gone0000", "dependency_type": "incompatible" }
+                ],
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "FAPI0001",
+                "project_id": "P7dR8mSH",
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "dependencies": [],
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            }
+        ]
+        "###;
+        let projects: Vec<Project> =
+            serde_json::from_str(projects).expect("deserialize projects from json");
+        let versions: Vec<Version> =
+            serde_json::from_str(versions).expect("deserialize versions from json");
+
+        let (project_ids, version_ids) =
+            ProjectDependencies::referenced_ids(&projects[0], &versions);
+        assert_eq!(project_ids, ["P7dR8mSH", "gone0000"]);
+        assert_eq!(version_ids, ["FAPI0001"]);
+
+        let deps = ProjectDependencies::compute(&projects[0], &versions, &projects);
+        assert_eq!(deps.projects.len(), 1);
+        assert_eq!(deps.projects[0].id, "P7dR8mSH");
+        assert_eq!(deps.versions.len(), 1);
+        assert_eq!(deps.versions[0].id, "FAPI0001");
+    }
+
+    #[test]
+    fn test_modrinth_project_v2_fields() {
+        let json = r###"
+        {
+            "_id": "Wnxd13zP",
+            "slug": "clumps",
+            "description": "Clumps XP orbs together to reduce lag",
+            "translated_description": "将经验球聚集在一起以减少游戏卡顿。",
+            "moderator_message": {
+                "message": "Please add a source link",
+                "body": null
+            },
+            "organization": "4reLOAKe",
+            "license": {
+                "id": "LicenseRef-Custom",
+                "name": "Custom",
+                "url": "https://example.com/LICENSE"
+            },
+            "game_versions": ["1.21.4"],
+            "loaders": ["fabric", "neoforge"],
+            "team": "3TidTIHz",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+
+        let p: Project = serde_json::from_str(json).expect("deserialize project from json");
+        assert_eq!(
+            p.translated_description.as_deref(),
+            Some("将经验球聚集在一起以减少游戏卡顿。")
+        );
+        let message = p.moderator_message.expect("moderator message");
+        assert_eq!(message.message, "Please add a source link");
+        assert_eq!(p.organization.as_deref(), Some("4reLOAKe"));
+        assert_eq!(
+            p.game_versions.as_deref(),
+            Some(&["1.21.4".to_string()][..])
+        );
+        assert_eq!(p.loaders.map(|l| l.len()), Some(2));
+        assert_eq!(
+            p.license.and_then(|l| l.effective_url()).as_deref(),
+            Some("https://example.com/LICENSE")
+        );
+
+        let custom = License {
+            id: Some("LicenseRef-All-Rights-Reserved".to_string()),
+            name: None,
+            url: None,
+        };
+        assert!(custom.effective_url().is_none());
+    }
+
+    #[test]
+    fn test_modrinth_version_ordering() {
+        let json = r###"
+        {
+            "_id": "1ZHtT6Xo",
+            "project_id": "Wnxd13zP",
+            "author_id": "l45nT5ov",
+            "ordering": 3,
+            "date_published": { "$date": "2024-12-22T23:43:33Z" },
+            "downloads": 0,
+            "files": [],
+            "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+        }
+        "###;
+
+        let v: Version = serde_json::from_str(json).expect("deserialize version from json");
+        assert_eq!(v.ordering, Some(3));
+        assert!(v.slug.is_none());
+    }
+
+    #[test]
+    fn test_modrinth_file_info_conversion() {
+        let json = r###"
+        {
+            "_id": "1ZHtT6Xo",
+            "project_id": "Wnxd13zP",
+            "author_id": "l45nT5ov",
+            "date_published": { "$date": "2024-12-22T23:43:33Z" },
+            "downloads": 12260,
+            "files": [
+                {
+                    "hashes": {
+                        "sha512": "86909659af2f4b481ae9b230996e86658e622424e28b808d069144bf116bf47191df74cfec8b88bbc37ec9ad8cf5a4a24a0f21b39d6c456132331881c8575aeb",
+                        "sha1": "d1f522452cfa1286349525ccace065a8ec7eb940"
+                    },
+                    "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/1ZHtT6Xo/Clumps-fabric-1.21.4-22.0.0.1.jar",
+                    "filename": "Clumps-fabric-1.21.4-22.0.0.1.jar",
+                    "primary": true,
+                    "size": 20721,
+                    "file_type": null
+                }
+            ],
+            "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+        }
+        "###;
+
+        let v: Version = serde_json::from_str(json).expect("deserialize version from json");
+        let files = v.to_files();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].version_id, "1ZHtT6Xo");
+        assert_eq!(files[0].project_id, "Wnxd13zP");
+        assert_eq!(files[0].sync_at, v.sync_at);
+        assert_eq!(files[0].size, 20721);
+
+        let info = files[0].info();
+        assert_eq!(info.filename, v.files[0].filename);
+        let back = File::from_info(info, files[0].context());
+        assert_eq!(back.hashes.sha1, files[0].hashes.sha1);
+        assert_eq!(back.version_id, files[0].version_id);
+    }
+
+    #[test]
+    fn test_modrinth_category_catalog() {
+        let categories = r###"
+        [
+            { "icon": "", "name": "adventure", "project_type": "mod", "header": "categories", "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } },
+            { "icon": "", "name": "library", "project_type": "mod", "header": "categories", "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } },
+            { "icon": "", "name": "atmosphere", "project_type": "shader", "header": "features", "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } },
+            { "icon": "", "name": "cartoon", "project_type": "shader", "header": "categories", "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } },
+            { "icon": "", "name": "high", "project_type": "shader", "header": "performance impact", "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } },
+            { "icon": "", "name": "16x", "project_type": "resourcepack", "header": "resolutions", "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } }
+        ]
+        "###;
+        let loaders = r###"
+        [
+            { "icon": "", "name": "fabric", "supported_project_types": ["mod", "modpack"], "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } },
+            { "icon": "", "name": "iris", "supported_project_types": ["shader"], "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } },
+            { "icon": "", "name": "optifine", "supported_project_types": ["shader"], "sync_at": { "$date": "2025-01-26T09:11:24.542Z" } }
+        ]
+        "###;
+        let categories: Vec<Category> =
+            serde_json::from_str(categories).expect("deserialize categories from json");
+        let loaders: Vec<Loader> =
+            serde_json::from_str(loaders).expect("deserialize loaders from json");
+        let catalog = CategoryCatalog::new(categories, loaders);
+
+        assert_eq!(
+            catalog.headers(),
+            [
+                "categories",
+                "features",
+                "performance impact",
+                "resolutions"
+            ]
+        );
+        let shader: Vec<&str> = catalog
+            .for_project_type("shader")
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(shader, ["atmosphere", "cartoon", "high"]);
+        assert_eq!(catalog.for_header("features", "mod").len(), 0);
+        assert_eq!(catalog.loaders_for("shader").len(), 2);
+
+        let json = r###"
+        {
+            "_id": "HVnmMxH1",
+            "slug": "complementary-reimagined",
+            "project_type": "shader",
+            "categories": ["cartoon", "high"],
+            "additional_categories": ["atmosphere", "library"],
+            "loaders": ["iris", "optifine", "fabric", "canvas"],
+            "team": "3TidTIHz",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+        let p: Project = serde_json::from_str(json).expect("deserialize project from json");
+
+        let grouped = catalog.group(&p);
+        let groups: Vec<(&str, Vec<&str>, Vec<&str>)> = grouped
+            .groups
+            .iter()
+            .map(|g| {
+                (
+                    g.header,
+                    g.categories.iter().map(|c| c.name.as_str()).collect(),
+                    g.additional.iter().map(|c| c.name.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                ("categories", vec!["cartoon"], vec![]),
+                ("features", vec![], vec!["atmosphere"]),
+                ("performance impact", vec!["high"], vec![]),
+            ]
+        );
+        assert_eq!(grouped.unknown, ["library"]);
+        assert_eq!(catalog.unsupported_loaders(&p), ["fabric", "canvas"]);
+    }
 }
@@ -0,0 +1,915 @@
+use chrono::{DateTime, Utc};
+
+use crate::minecraft::McVersion;
+use crate::{curseforge, modrinth};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    Modrinth,
+    CurseForge,
+}
+
+/// Release channel, ordered from most to least stable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReleaseChannel {
+    Release,
+    Beta,
+    Alpha,
+}
+
+impl ReleaseChannel {
+    pub fn from_modrinth(version_type: &str) -> Option<Self> {
+        match version_type {
+            "release" => Some(ReleaseChannel::Release),
+            "beta" => Some(ReleaseChannel::Beta),
+            "alpha" => Some(ReleaseChannel::Alpha),
+            _ => None,
+        }
+    }
+
+    pub fn from_curseforge(release_type: i32) -> Option<Self> {
+        match release_type {
+            1 => Some(ReleaseChannel::Release),
+            2 => Some(ReleaseChannel::Beta),
+            3 => Some(ReleaseChannel::Alpha),
+            _ => None,
+        }
+    }
+
+    /// Whether a file on this channel is acceptable to a user who allows
+    /// `min` and anything more stable.
+    pub fn satisfies(&self, min: ReleaseChannel) -> bool {
+        *self <= min
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorRef {
+    pub id: String,
+    pub name: String,
+    pub url: Option<String>,
+}
+
+pub trait ProjectLike {
+    fn platform(&self) -> Platform;
+    fn id(&self) -> String;
+    fn slug(&self) -> &str;
+    fn name(&self) -> Option<&str>;
+    fn summary(&self) -> Option<&str>;
+    fn icon_url(&self) -> Option<&str>;
+    fn downloads(&self) -> i64;
+    /// `None` when the authors are not part of the project document: Modrinth
+    /// projects only reference a team, see `AnyProject::with_team`.
+    fn authors(&self) -> Option<Vec<AuthorRef>>;
+    fn categories(&self) -> Vec<String>;
+    fn game_versions(&self) -> Vec<String>;
+    fn loaders(&self) -> Vec<String>;
+    fn created_at(&self) -> Option<DateTime<Utc>>;
+    fn updated_at(&self) -> Option<DateTime<Utc>>;
+    fn page_url(&self) -> Option<String>;
+}
+
+pub trait FileLike {
+    fn platform(&self) -> Platform;
+    fn id(&self) -> String;
+    fn project_id(&self) -> String;
+    fn file_name(&self) -> Option<&str>;
+    fn download_url(&self) -> Option<&str>;
+    fn size(&self) -> Option<i64>;
+    fn channel(&self) -> Option<ReleaseChannel>;
+    fn game_versions(&self) -> Vec<String>;
+    fn loaders(&self) -> Vec<String>;
+    fn published_at(&self) -> Option<DateTime<Utc>>;
+}
+
+impl modrinth::Project {
+    /// Accepted members of `team` in display order, or `None` when `team` is
+    /// not this project's team.
+    pub fn authors_with(&self, team: &modrinth::Team) -> Option<Vec<AuthorRef>> {
+        if team.id != self.team {
+            return None;
+        }
+        let authors = self
+            .members(team)
+            .into_iter()
+            .map(|m| AuthorRef {
+                id: m.user.id.clone(),
+                name: m.user.username.clone(),
+                url: Some(format!("https://modrinth.com/user/{}", m.user.username)),
+            })
+            .collect();
+        Some(authors)
+    }
+}
+
+impl ProjectLike for modrinth::Project {
+    fn platform(&self) -> Platform {
+        Platform::Modrinth
+    }
+
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn summary(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn icon_url(&self) -> Option<&str> {
+        self.icon_url.as_deref()
+    }
+
+    fn downloads(&self) -> i64 {
+        self.downloads.unwrap_or(0)
+    }
+
+    fn authors(&self) -> Option<Vec<AuthorRef>> {
+        None
+    }
+
+    fn categories(&self) -> Vec<String> {
+        let categories = self.categories.iter().flatten();
+        let additional = self.additional_categories.iter().flatten();
+        categories.chain(additional).cloned().collect()
+    }
+
+    fn game_versions(&self) -> Vec<String> {
+        self.game_versions.clone().unwrap_or_default()
+    }
+
+    fn loaders(&self) -> Vec<String> {
+        self.loaders.clone().unwrap_or_default()
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        Some(self.published)
+    }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        Some(self.updated)
+    }
+
+    fn page_url(&self) -> Option<String> {
+        let project_type = self.project_type.as_deref().unwrap_or("project");
+        Some(format!(
+            "https://modrinth.com/{}/{}",
+            project_type, self.slug
+        ))
+    }
+}
+
+impl ProjectLike for curseforge::Mod {
+    fn platform(&self) -> Platform {
+        Platform::CurseForge
+    }
+
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    fn icon_url(&self) -> Option<&str> {
+        self.logo.as_ref()?.url.as_deref()
+    }
+
+    fn downloads(&self) -> i64 {
+        self.download_count.unwrap_or(0)
+    }
+
+    fn authors(&self) -> Option<Vec<AuthorRef>> {
+        let authors = self.authors.as_ref()?;
+        Some(
+            authors
+                .iter()
+                .map(|a| AuthorRef {
+                    id: a.id.to_string(),
+                    name: a.name.clone(),
+                    url: a.url.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    fn categories(&self) -> Vec<String> {
+        self.categories
+            .iter()
+            .flatten()
+            .filter_map(|c| c.name.clone())
+            .collect()
+    }
+
+    fn game_versions(&self) -> Vec<String> {
+        let indexes = self.latest_files_indexes.iter().flatten();
+        let mut versions: Vec<String> = Vec::new();
+        for version in indexes.filter_map(|i| i.game_version.as_ref()) {
+            if !versions.contains(version) {
+                versions.push(version.clone());
+            }
+        }
+        versions
+    }
+
+    fn loaders(&self) -> Vec<String> {
+        let indexes = self.latest_files_indexes.iter().flatten();
+        let mut loaders: Vec<String> = Vec::new();
+        for loader in indexes.filter_map(|i| i.loader()) {
+            if loader != curseforge::ModLoaderType::Any
+                && !loaders.iter().any(|l| l == loader.name())
+            {
+                loaders.push(loader.name().to_string());
+            }
+        }
+        loaders
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.date_created
+    }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.date_modified
+    }
+
+    fn page_url(&self) -> Option<String> {
+        self.links.as_ref()?.website_url.clone()
+    }
+}
+
+impl FileLike for modrinth::Version {
+    fn platform(&self) -> Platform {
+        Platform::Modrinth
+    }
+
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn project_id(&self) -> String {
+        self.project_id.clone()
+    }
+
+    fn file_name(&self) -> Option<&str> {
+        self.primary_file().map(|f| f.filename.as_str())
+    }
+
+    fn download_url(&self) -> Option<&str> {
+        self.primary_file().map(|f| f.url.as_str())
+    }
+
+    fn size(&self) -> Option<i64> {
+        self.primary_file().map(|f| f.size)
+    }
+
+    fn channel(&self) -> Option<ReleaseChannel> {
+        self.version_type
+            .as_deref()
+            .and_then(ReleaseChannel::from_modrinth)
+    }
+
+    fn game_versions(&self) -> Vec<String> {
+        self.game_versions.clone().unwrap_or_default()
+    }
+
+    fn loaders(&self) -> Vec<String> {
+        self.loaders.clone().unwrap_or_default()
+    }
+
+    fn published_at(&self) -> Option<DateTime<Utc>> {
+        Some(self.date_published)
+    }
+}
+
+/// Standalone Modrinth files carry no version metadata, so channel, game
+/// versions, loaders and dates are empty; use the owning `Version` instead.
+impl FileLike for modrinth::File {
+    fn platform(&self) -> Platform {
+        Platform::Modrinth
+    }
+
+    fn id(&self) -> String {
+        self.hashes.sha1.clone()
+    }
+
+    fn project_id(&self) -> String {
+        self.project_id.clone()
+    }
+
+    fn file_name(&self) -> Option<&str> {
+        Some(&self.filename)
+    }
+
+    fn download_url(&self) -> Option<&str> {
+        Some(&self.url)
+    }
+
+    fn size(&self) -> Option<i64> {
+        Some(self.size)
+    }
+
+    fn channel(&self) -> Option<ReleaseChannel> {
+        None
+    }
+
+    fn game_versions(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn loaders(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn published_at(&self) -> Option<DateTime<Utc>> {
+        None
+    }
+}
+
+impl FileLike for curseforge::File {
+    fn platform(&self) -> Platform {
+        Platform::CurseForge
+    }
+
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn project_id(&self) -> String {
+        self.mod_id.to_string()
+    }
+
+    fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    fn download_url(&self) -> Option<&str> {
+        self.download_url.as_deref()
+    }
+
+    fn size(&self) -> Option<i64> {
+        self.file_length
+    }
+
+    fn channel(&self) -> Option<ReleaseChannel> {
+        self.release_type.and_then(ReleaseChannel::from_curseforge)
+    }
+
+    fn game_versions(&self) -> Vec<String> {
+        self.game_version_tags().minecraft
+    }
+
+    fn loaders(&self) -> Vec<String> {
+        self.game_version_tags()
+            .loaders
+            .iter()
+            .map(|l| l.name().to_string())
+            .collect()
+    }
+
+    fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.file_date
+    }
+}
+
+impl curseforge::Mod {
+    /// Newest `latest_files_indexes` entry for `game_version` whose release
+    /// type is `min_channel` or more stable, with its `latest_files` details
+    /// when present. Entries whose details mark the file as unavailable are
+    /// skipped. See `best_file_in` for the selection rule.
+    pub fn best_file(
+        &self,
+        game_version: &McVersion,
+        loader: Option<curseforge::ModLoaderType>,
+        min_channel: ReleaseChannel,
+    ) -> Option<BestFile<'_>> {
+        let entries = self.latest_files_indexes.iter().flatten().filter(|i| {
+            self.latest_file(i.file_id)
+                .is_none_or(|f| f.is_available != Some(false))
+        });
+        let index = curseforge::Mod::best_file_in(entries, game_version, loader, min_channel)?;
+        let file = self.latest_file(index.file_id);
+        Some(BestFile { index, file })
+    }
+
+    /// The rule behind `best_file`, over any `latest_files_indexes`-style
+    /// entries. Entries for `loader` are preferred; entries without a
+    /// loader (or `Any`) are the fallback. `None` accepts every loader.
+    /// Unknown release types count as alpha, and the highest file id is
+    /// the newest.
+    pub fn best_file_in<'a>(
+        entries: impl IntoIterator<Item = &'a curseforge::FileIndex>,
+        game_version: &McVersion,
+        loader: Option<curseforge::ModLoaderType>,
+        min_channel: ReleaseChannel,
+    ) -> Option<&'a curseforge::FileIndex> {
+        let candidates: Vec<&curseforge::FileIndex> = entries
+            .into_iter()
+            .filter(|i| {
+                i.release_type
+                    .and_then(ReleaseChannel::from_curseforge)
+                    .unwrap_or(ReleaseChannel::Alpha)
+                    .satisfies(min_channel)
+                    && i.game_version
+                        .as_deref()
+                        .is_some_and(|v| McVersion::parse(v).is_ok_and(|v| v == *game_version))
+            })
+            .collect();
+        let exact = candidates
+            .iter()
+            .filter(|i| loader.is_none() || i.loader() == loader)
+            .max_by_key(|i| i.file_id);
+        exact
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .filter(|i| matches!(i.loader(), None | Some(curseforge::ModLoaderType::Any)))
+                    .max_by_key(|i| i.file_id)
+            })
+            .copied()
+    }
+}
+
+/// Result of `curseforge::Mod::best_file`.
+#[derive(Debug, Clone, Copy)]
+pub struct BestFile<'a> {
+    pub index: &'a curseforge::FileIndex,
+    pub file: Option<&'a curseforge::FileInfo>,
+}
+
+impl BestFile<'_> {
+    pub fn file_id(&self) -> i32 {
+        self.index.file_id
+    }
+
+    /// Whether this is the mod's `main_file_id`.
+    pub fn is_main(&self, m: &curseforge::Mod) -> bool {
+        m.main_file_id == Some(self.index.file_id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AnyProject {
+    Modrinth {
+        project: Box<modrinth::Project>,
+        team: Option<Box<modrinth::Team>>,
+    },
+    CurseForge(Box<curseforge::Mod>),
+}
+
+impl AnyProject {
+    /// Attaches the team of a Modrinth project so `authors` can resolve it;
+    /// CurseForge mods embed their authors and are returned unchanged.
+    pub fn with_team(self, team: modrinth::Team) -> Self {
+        match self {
+            AnyProject::Modrinth { project, .. } => AnyProject::Modrinth {
+                project,
+                team: Some(Box::new(team)),
+            },
+            other => other,
+        }
+    }
+
+    pub fn as_modrinth(&self) -> Option<&modrinth::Project> {
+        match self {
+            AnyProject::Modrinth { project, .. } => Some(project),
+            _ => None,
+        }
+    }
+
+    pub fn as_curseforge(&self) -> Option<&curseforge::Mod> {
+        match self {
+            AnyProject::CurseForge(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    fn inner(&self) -> &dyn ProjectLike {
+        match self {
+            AnyProject::Modrinth { project, .. } => project.as_ref(),
+            AnyProject::CurseForge(m) => m.as_ref(),
+        }
+    }
+}
+
+impl From<modrinth::Project> for AnyProject {
+    fn from(project: modrinth::Project) -> Self {
+        AnyProject::Modrinth {
+            project: Box::new(project),
+            team: None,
+        }
+    }
+}
+
+impl From<curseforge::Mod> for AnyProject {
+    fn from(m: curseforge::Mod) -> Self {
+        AnyProject::CurseForge(Box::new(m))
+    }
+}
+
+impl ProjectLike for AnyProject {
+    fn platform(&self) -> Platform {
+        self.inner().platform()
+    }
+
+    fn id(&self) -> String {
+        self.inner().id()
+    }
+
+    fn slug(&self) -> &str {
+        self.inner().slug()
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.inner().name()
+    }
+
+    fn summary(&self) -> Option<&str> {
+        self.inner().summary()
+    }
+
+    fn icon_url(&self) -> Option<&str> {
+        self.inner().icon_url()
+    }
+
+    fn downloads(&self) -> i64 {
+        self.inner().downloads()
+    }
+
+    fn authors(&self) -> Option<Vec<AuthorRef>> {
+        match self {
+            AnyProject::Modrinth {
+                project,
+                team: Some(team),
+            } => project.authors_with(team),
+            _ => self.inner().authors(),
+        }
+    }
+
+    fn categories(&self) -> Vec<String> {
+        self.inner().categories()
+    }
+
+    fn game_versions(&self) -> Vec<String> {
+        self.inner().game_versions()
+    }
+
+    fn loaders(&self) -> Vec<String> {
+        self.inner().loaders()
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.inner().created_at()
+    }
+
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.inner().updated_at()
+    }
+
+    fn page_url(&self) -> Option<String> {
+        self.inner().page_url()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AnyFile {
+    ModrinthVersion(modrinth::Version),
+    ModrinthFile(modrinth::File),
+    CurseForge(curseforge::File),
+}
+
+impl AnyFile {
+    fn inner(&self) -> &dyn FileLike {
+        match self {
+            AnyFile::ModrinthVersion(version) => version,
+            AnyFile::ModrinthFile(file) => file,
+            AnyFile::CurseForge(file) => file,
+        }
+    }
+}
+
+impl From<modrinth::Version> for AnyFile {
+    fn from(version: modrinth::Version) -> Self {
+        AnyFile::ModrinthVersion(version)
+    }
+}
+
+impl From<modrinth::File> for AnyFile {
+    fn from(file: modrinth::File) -> Self {
+        AnyFile::ModrinthFile(file)
+    }
+}
+
+impl From<curseforge::File> for AnyFile {
+    fn from(file: curseforge::File) -> Self {
+        AnyFile::CurseForge(file)
+    }
+}
+
+impl FileLike for AnyFile {
+    fn platform(&self) -> Platform {
+        self.inner().platform()
+    }
+
+    fn id(&self) -> String {
+        self.inner().id()
+    }
+
+    fn project_id(&self) -> String {
+        self.inner().project_id()
+    }
+
+    fn file_name(&self) -> Option<&str> {
+        self.inner().file_name()
+    }
+
+    fn download_url(&self) -> Option<&str> {
+        self.inner().download_url()
+    }
+
+    fn size(&self) -> Option<i64> {
+        self.inner().size()
+    }
+
+    fn channel(&self) -> Option<ReleaseChannel> {
+        self.inner().channel()
+    }
+
+    fn game_versions(&self) -> Vec<String> {
+        self.inner().game_versions()
+    }
+
+    fn loaders(&self) -> Vec<String> {
+        self.inner().loaders()
+    }
+
+    fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.inner().published_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_any_project() {
+        let modrinth = r###"
+        {
+            "_id": "Ua7DFN59",
+            "slug": "yungs-api",
+            "title": "YUNG's API",
+            "description": "Library mod for YUNG's mods.",
+            "project_type": "mod",
+            "categories": ["library"],
+            "additional_categories": ["worldgen"],
+            "loaders": ["fabric", "forge"],
+            "game_versions": ["1.20.1"],
+            "downloads": 9979426,
+            "team": "3TidTIHz",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+        let curseforge = r###"
+        {
+            "_id": 594678,
+            "slug": "project-594678",
+            "name": "Hats and Cosmetics",
+            "summary": "Wearable and fashionable Cosmetics!",
+            "downloadCount": 190029,
+            "authors": [
+                { "id": 100247822, "name": "purplik", "url": "https://www.curseforge.com/members/purplik" }
+            ],
+            "categories": [{ "id": 424, "name": "Cosmetic" }],
+            "latestFilesIndexes": [
+                { "gameVersion": "1.19.2", "fileId": 4019148, "releaseType": 1, "modLoader": 1 },
+                { "gameVersion": "1.19.2", "fileId": 4019149, "releaseType": 1, "modLoader": 4 },
+                { "gameVersion": "1.18.2", "fileId": 4285139, "releaseType": 1, "modLoader": 1 }
+            ],
+            "links": { "websiteUrl": "https://www.curseforge.com/minecraft/mc-mods/project-594678" },
+            "dateCreated": { "$date": "2022-03-17T17:57:05.42Z" },
+            "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+        }
+        "###;
+
+        let team = r###"
+        {
+            "_id": "3TidTIHz",
+            "members": [
+                {
+                    "team_id": "3TidTIHz",
+                    "user": {
+                        "id": "KR4hBMaR",
+                        "username": "YUNGNICKYOUNG",
+                        "avatar_url": "https://cdn.modrinth.com/user/KR4hBMaR/avatar.png",
+                        "bio": null,
+                        "created": { "$date": "2020-12-06T01:24:51.384Z" },
+                        "role": "developer"
+                    },
+                    "role": "Owner",
+                    "permissions": 1023,
+                    "accepted": true,
+                    "payouts_split": 100.0,
+                    "ordering": 0
+                },
+                {
+                    "team_id": "3TidTIHz",
+                    "user": {
+                        "id": "aaaaaaaa",
+                        "username": "Invited",
+                        "created": null,
+                        "role": "developer"
+                    },
+                    "role": "Member",
+                    "permissions": null,
+                    "accepted": false,
+                    "payouts_split": null,
+                    "ordering": 1
+                }
+            ],
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+
+        let projects: Vec<AnyProject> = vec![
+            serde_json::from_str::<modrinth::Project>(modrinth)
+                .expect("deserialize project from json")
+                .into(),
+            serde_json::from_str::<curseforge::Mod>(curseforge)
+                .expect("deserialize mod from json")
+                .into(),
+        ];
+
+        assert_eq!(projects[0].platform(), Platform::Modrinth);
+        assert_eq!(projects[0].id(), "Ua7DFN59");
+        assert_eq!(projects[0].categories(), ["library", "worldgen"]);
+        assert_eq!(
+            projects[0].page_url().as_deref(),
+            Some("https://modrinth.com/mod/yungs-api")
+        );
+        assert!(projects[0].authors().is_none());
+        assert_eq!(
+            projects[0].as_modrinth().map(|p| p.team.as_str()),
+            Some("3TidTIHz")
+        );
+
+        let team: modrinth::Team = serde_json::from_str(team).expect("deserialize team from json");
+        let mut other_team = team.clone();
+        other_team.id = "9zLxbp3P".to_string();
+        let with_team = projects[0].clone().with_team(team);
+        assert_eq!(
+            with_team.authors(),
+            Some(vec![AuthorRef {
+                id: "KR4hBMaR".to_string(),
+                name: "YUNGNICKYOUNG".to_string(),
+                url: Some("https://modrinth.com/user/YUNGNICKYOUNG".to_string()),
+            }])
+        );
+        assert!(
+            projects[0]
+                .clone()
+                .with_team(other_team)
+                .authors()
+                .is_none()
+        );
+
+        assert_eq!(projects[1].id(), "594678");
+        assert_eq!(projects[1].name(), Some("Hats and Cosmetics"));
+        assert_eq!(
+            projects[1].authors().expect("curseforge authors")[0].name,
+            "purplik"
+        );
+        assert_eq!(projects[1].game_versions(), ["1.19.2", "1.18.2"]);
+        assert_eq!(projects[1].loaders(), ["forge", "fabric"]);
+        assert!(projects[1].updated_at().is_none());
+        assert!(projects[1].as_modrinth().is_none());
+    }
+
+    #[test]
+    fn test_any_file() {
+        let json = r###"
+        {
+            "_id": 3913840,
+            "gameId": 432,
+            "modId": 594678,
+            "fileName": "hats-and-cosmetics-1.2.2-1.19.1.jar",
+            "releaseType": 1,
+            "fileLength": 120660,
+            "downloadUrl": "https://edge.forgecdn.net/files/3913/840/hats-and-cosmetics-1.2.2-1.19.1.jar",
+            "gameVersions": ["Forge", "1.19.1"],
+            "fileDate": { "$date": "2022-08-03T06:26:20Z" },
+            "sync_at": { "$date": "2024-10-26T12:33:55Z" }
+        }
+        "###;
+
+        let file: AnyFile = serde_json::from_str::<curseforge::File>(json)
+            .expect("deserialize file from json")
+            .into();
+        assert_eq!(file.platform(), Platform::CurseForge);
+        assert_eq!(file.project_id(), "594678");
+        assert_eq!(file.channel(), Some(ReleaseChannel::Release));
+        assert_eq!(file.game_versions(), ["1.19.1"]);
+        assert_eq!(file.loaders(), ["forge"]);
+        assert_eq!(file.size(), Some(120660));
+        assert!(ReleaseChannel::Release.satisfies(ReleaseChannel::Beta));
+        assert!(!ReleaseChannel::Alpha.satisfies(ReleaseChannel::Beta));
+    }
+
+    #[test]
+    fn test_curseforge_best_file() {
+        let json = r###"
+        {
+            "_id": 594678,
+            "slug": "hats-and-cosmetics",
+            "mainFileId": 4003,
+            "latestFiles": [
+                {
+                    "id": 4003,
+                    "gameId": 432,
+                    "modId": 594678,
+                    "displayName": "hats-and-cosmetics-1.3.0-1.20.1",
+                    "releaseType": 1
+                }
+            ],
+            "latestFilesIndexes": [
+                { "gameVersion": "1.20.1", "fileId": 4004, "filename": "hats-beta.jar", "releaseType": 2, "modLoader": 4 },
+                { "gameVersion": "1.20.1", "fileId": 4003, "filename": "hats-1.3.0.jar", "releaseType": 1, "modLoader": 4 },
+                { "gameVersion": "1.20.1", "fileId": 4002, "filename": "hats-forge.jar", "releaseType": 1, "modLoader": 1 },
+                { "gameVersion": "1.19.2", "fileId": 3001, "filename": "hats-any.jar", "releaseType": 1 }
+            ],
+            "sync_at": {
+                "$date": "2025-06-17T10:46:29.504Z"
+            }
+        }
+        "###;
+        let m: curseforge::Mod = serde_json::from_str(json).expect("deserialize mod from json");
+        let v = |s: &str| McVersion::parse(s).expect("version");
+
+        let best = m
+            .best_file(
+                &v("1.20.1"),
+                Some(curseforge::ModLoaderType::Fabric),
+                ReleaseChannel::Release,
+            )
+            .expect("best file");
+        assert_eq!(best.file_id(), 4003);
+        assert!(best.is_main(&m));
+        assert_eq!(
+            best.file.and_then(|f| f.display_name.as_deref()),
+            Some("hats-and-cosmetics-1.3.0-1.20.1")
+        );
+
+        let beta = m
+            .best_file(
+                &v("1.20.1"),
+                Some(curseforge::ModLoaderType::Fabric),
+                ReleaseChannel::Beta,
+            )
+            .expect("best file");
+        assert_eq!(beta.file_id(), 4004);
+        assert!(beta.file.is_none());
+
+        let any = m
+            .best_file(&v("1.20.1"), None, ReleaseChannel::Release)
+            .expect("best file");
+        assert_eq!(any.file_id(), 4003);
+
+        let fallback = m
+            .best_file(
+                &v("1.19.2"),
+                Some(curseforge::ModLoaderType::Quilt),
+                ReleaseChannel::Release,
+            )
+            .expect("best file");
+        assert_eq!(fallback.file_id(), 3001);
+
+        assert!(
+            m.best_file(
+                &v("1.20.1"),
+                Some(curseforge::ModLoaderType::Quilt),
+                ReleaseChannel::Alpha
+            )
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,961 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::minecraft::McVersion;
+use crate::mirror::Lookup;
+use crate::platform::ReleaseChannel;
+use crate::{curseforge, modrinth};
+
+const REQUIRED: &str = "required";
+const INCOMPATIBLE: &str = "incompatible";
+
+/// The game version, loader and least stable release channel a mod list
+/// is being resolved for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    pub game_version: McVersion,
+    pub loader: String,
+    pub min_channel: ReleaseChannel,
+}
+
+impl Target {
+    pub fn new(game_version: McVersion, loader: &str) -> Self {
+        Target {
+            game_version,
+            loader: loader.to_ascii_lowercase(),
+            min_channel: ReleaseChannel::Release,
+        }
+    }
+
+    pub fn with_min_channel(mut self, min_channel: ReleaseChannel) -> Self {
+        self.min_channel = min_channel;
+        self
+    }
+
+    fn has_game_version(&self, versions: &[String]) -> bool {
+        versions
+            .iter()
+            .any(|v| McVersion::parse(v).is_ok_and(|v| v == self.game_version))
+    }
+
+    fn has_loader<S: AsRef<str>>(&self, loaders: &[S]) -> bool {
+        loaders
+            .iter()
+            .any(|l| l.as_ref().eq_ignore_ascii_case(&self.loader))
+    }
+
+    /// Versions with an unknown `version_type` are treated as alpha.
+    pub fn accepts_modrinth(&self, version: &modrinth::Version) -> bool {
+        let channel = version
+            .version_type
+            .as_deref()
+            .and_then(ReleaseChannel::from_modrinth)
+            .unwrap_or(ReleaseChannel::Alpha);
+        channel.satisfies(self.min_channel)
+            && self.has_game_version(version.game_versions.as_deref().unwrap_or_default())
+            && self.has_loader(version.loaders.as_deref().unwrap_or_default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MissingReason {
+    /// No versions of the project are stored.
+    ProjectNotStored,
+    /// A dependency pinned a version id that is not stored.
+    VersionNotStored,
+    /// Versions are stored but none matches the target.
+    NoCompatibleVersion,
+    /// A dependency pinned a version that does not match the target.
+    PinnedVersionIncompatible,
+    /// A dependency pinned a version that belongs to another project.
+    PinnedVersionOfOtherProject,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModrinthMissing {
+    pub project_id: Option<String>,
+    pub version_id: Option<String>,
+    /// Version id of the dependent, `None` for requested projects.
+    pub required_by: Option<String>,
+    pub reason: MissingReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModrinthConflict {
+    /// `version_id` declares `incompatible_with` (a selected version) as
+    /// incompatible.
+    Incompatible {
+        version_id: String,
+        incompatible_with: String,
+    },
+    /// `required_by` pins `wanted` but `selected` was already chosen for the
+    /// same project.
+    VersionMismatch {
+        project_id: String,
+        selected: String,
+        wanted: String,
+        required_by: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ModrinthResolution<'a> {
+    /// Selected versions, requested projects first, then dependencies in
+    /// the order they were discovered.
+    pub versions: Vec<&'a modrinth::Version>,
+    pub missing: Vec<ModrinthMissing>,
+    pub conflicts: Vec<ModrinthConflict>,
+}
+
+impl ModrinthResolution<'_> {
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.conflicts.is_empty()
+    }
+
+    pub fn version_of(&self, project_id: &str) -> Option<&modrinth::Version> {
+        self.versions
+            .iter()
+            .find(|v| v.project_id == project_id)
+            .copied()
+    }
+}
+
+struct Request {
+    project_id: Option<String>,
+    version_id: Option<String>,
+    required_by: Option<String>,
+}
+
+/// Resolves required dependencies over stored `modrinth::Version`s. The
+/// first version chosen for a project is kept; later demands for another
+/// version of it are reported as conflicts rather than backtracked.
+/// Unpinned projects get their newest version that declares no
+/// incompatibility with the versions selected so far, in either direction;
+/// pinned versions and projects with no such version are still selected
+/// and reported as `ModrinthConflict::Incompatible`.
+#[derive(Debug, Clone)]
+pub struct ModrinthResolver<'a> {
+    by_id: HashMap<&'a str, &'a modrinth::Version>,
+    by_project: HashMap<&'a str, Vec<&'a modrinth::Version>>,
+    target: Target,
+}
+
+impl<'a> ModrinthResolver<'a> {
+    pub fn new(versions: &'a [modrinth::Version], target: Target) -> Self {
+        let mut by_id = HashMap::new();
+        let mut by_project: HashMap<&str, Vec<&modrinth::Version>> = HashMap::new();
+        for v in versions {
+            by_id.insert(v.id.as_str(), v);
+            by_project.entry(v.project_id.as_str()).or_default().push(v);
+        }
+        ModrinthResolver {
+            by_id,
+            by_project,
+            target,
+        }
+    }
+
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    /// Newest version of `project_id` compatible with the target, see
+    /// `mod_version::release_keys`.
+    pub fn newest(&self, project_id: &str) -> Option<&'a modrinth::Version> {
+        let versions = self.by_project.get(project_id)?;
+        modrinth::Version::newest(
+            versions
+                .iter()
+                .copied()
+                .filter(|v| self.target.accepts_modrinth(v)),
+        )
+    }
+
+    pub fn resolve<S: AsRef<str>>(&self, project_ids: &[S]) -> ModrinthResolution<'a> {
+        let mut resolution = ModrinthResolution::default();
+        let mut selected: HashMap<&str, &modrinth::Version> = HashMap::new();
+        let mut queue: VecDeque<Request> = project_ids
+            .iter()
+            .map(|id| Request {
+                project_id: Some(id.as_ref().to_string()),
+                version_id: None,
+                required_by: None,
+            })
+            .collect();
+
+        while let Some(request) = queue.pop_front() {
+            let Some(version) = self.select(&request, &selected, &mut resolution) else {
+                continue;
+            };
+            selected.insert(version.project_id.as_str(), version);
+            resolution.versions.push(version);
+            for dep in version.dependencies.iter().flatten() {
+                if dep.dependency_type == REQUIRED
+                    && (dep.project_id.is_some() || dep.version_id.is_some())
+                {
+                    queue.push_back(Request {
+                        project_id: dep.project_id.clone(),
+                        version_id: dep.version_id.clone(),
+                        required_by: Some(version.id.clone()),
+                    });
+                }
+            }
+        }
+
+        let selected_ids: HashSet<&str> =
+            resolution.versions.iter().map(|v| v.id.as_str()).collect();
+        for version in &resolution.versions {
+            for dep in version.dependencies.iter().flatten() {
+                if dep.dependency_type != INCOMPATIBLE {
+                    continue;
+                }
+                let hit = match (&dep.version_id, &dep.project_id) {
+                    (Some(id), _) => selected_ids.contains(id.as_str()).then(|| id.clone()),
+                    (None, Some(project)) => selected.get(project.as_str()).map(|v| v.id.clone()),
+                    (None, None) => None,
+                };
+                if let Some(incompatible_with) = hit {
+                    resolution.conflicts.push(ModrinthConflict::Incompatible {
+                        version_id: version.id.clone(),
+                        incompatible_with,
+                    });
+                }
+            }
+        }
+        resolution
+    }
+
+    fn select(
+        &self,
+        request: &Request,
+        selected: &HashMap<&str, &modrinth::Version>,
+        resolution: &mut ModrinthResolution<'a>,
+    ) -> Option<&'a modrinth::Version> {
+        let missing = |reason| ModrinthMissing {
+            project_id: request.project_id.clone(),
+            version_id: request.version_id.clone(),
+            required_by: request.required_by.clone(),
+            reason,
+        };
+
+        if let Some(version_id) = &request.version_id {
+            let Some(version) = self.by_id.get(version_id.as_str()).copied() else {
+                resolution
+                    .missing
+                    .push(missing(MissingReason::VersionNotStored));
+                return None;
+            };
+            if request
+                .project_id
+                .as_ref()
+                .is_some_and(|id| *id != version.project_id)
+            {
+                resolution
+                    .missing
+                    .push(missing(MissingReason::PinnedVersionOfOtherProject));
+                return None;
+            }
+            if let Some(current) = selected.get(version.project_id.as_str()) {
+                if current.id != version.id {
+                    resolution
+                        .conflicts
+                        .push(ModrinthConflict::VersionMismatch {
+                            project_id: version.project_id.clone(),
+                            selected: current.id.clone(),
+                            wanted: version.id.clone(),
+                            required_by: request.required_by.clone(),
+                        });
+                }
+                return None;
+            }
+            if !self.target.accepts_modrinth(version) {
+                resolution
+                    .missing
+                    .push(missing(MissingReason::PinnedVersionIncompatible));
+                return None;
+            }
+            return Some(version);
+        }
+
+        let project_id = request.project_id.as_deref()?;
+        if selected.contains_key(project_id) {
+            return None;
+        }
+        if !self.by_project.contains_key(project_id) {
+            resolution
+                .missing
+                .push(missing(MissingReason::ProjectNotStored));
+            return None;
+        }
+        let Some(newest) = self.newest(project_id) else {
+            resolution
+                .missing
+                .push(missing(MissingReason::NoCompatibleVersion));
+            return None;
+        };
+        let clashes = |v: &modrinth::Version| {
+            selected
+                .values()
+                .any(|s| declares_incompatible(v, s) || declares_incompatible(s, v))
+        };
+        if !clashes(newest) {
+            return Some(newest);
+        }
+        let fallback = modrinth::Version::newest(
+            self.by_project[project_id]
+                .iter()
+                .copied()
+                .filter(|v| self.target.accepts_modrinth(v) && !clashes(v)),
+        );
+        Some(fallback.unwrap_or(newest))
+    }
+}
+
+/// Whether `version` lists `other`, or its project, as incompatible.
+fn declares_incompatible(version: &modrinth::Version, other: &modrinth::Version) -> bool {
+    version.dependencies.iter().flatten().any(|dep| {
+        dep.dependency_type == INCOMPATIBLE
+            && match (&dep.version_id, &dep.project_id) {
+                (Some(id), _) => *id == other.id,
+                (None, Some(project)) => *project == other.project_id,
+                (None, None) => false,
+            }
+    })
+}
+
+impl Target {
+    fn curseforge_loader(&self) -> Option<curseforge::ModLoaderType> {
+        curseforge::ModLoaderType::from_name(&self.loader)
+    }
+
+    /// Files that list no loader at all (common before 1.14) are accepted
+    /// for any loader.
+    pub fn accepts_curseforge(&self, file: &curseforge::File) -> bool {
+        file.is_available != Some(false) && self.best_curseforge_index(&file.indexes()).is_some()
+    }
+
+    /// The entry `Mod::best_file_in` picks for this target. A loader name
+    /// CurseForge does not know only matches entries for any loader.
+    pub fn best_curseforge_index<'a>(
+        &self,
+        entries: impl IntoIterator<Item = &'a curseforge::FileIndex>,
+    ) -> Option<&'a curseforge::FileIndex> {
+        let loader = self
+            .curseforge_loader()
+            .unwrap_or(curseforge::ModLoaderType::Any);
+        curseforge::Mod::best_file_in(entries, &self.game_version, Some(loader), self.min_channel)
+    }
+
+    /// Entries without a loader, or with `Any`, match every loader.
+    pub fn accepts_curseforge_index(&self, index: &curseforge::FileIndex) -> bool {
+        self.best_curseforge_index([index]).is_some()
+    }
+}
+
+/// Read access to stored CurseForge documents.
+pub trait CurseForgeStore {
+    fn get_mod(&self, mod_id: i32) -> Option<&curseforge::Mod>;
+    fn get_file(&self, file_id: i32) -> Option<&curseforge::File>;
+    fn files_of(&self, mod_id: i32) -> Vec<&curseforge::File>;
+    /// The synced fingerprint match for a file's Murmur2 fingerprint.
+    fn fingerprint(&self, fingerprint: i64) -> Lookup<&curseforge::Fingerprint>;
+}
+
+/// A `CurseForgeStore` over already loaded documents.
+#[derive(Debug, Clone, Copy)]
+pub struct SliceStore<'a> {
+    pub mods: &'a [curseforge::Mod],
+    pub files: &'a [curseforge::File],
+    pub fingerprints: &'a [curseforge::Fingerprint],
+}
+
+impl<'a> SliceStore<'a> {
+    pub fn new(mods: &'a [curseforge::Mod], files: &'a [curseforge::File]) -> Self {
+        SliceStore {
+            mods,
+            files,
+            fingerprints: &[],
+        }
+    }
+
+    pub fn with_fingerprints(mut self, fingerprints: &'a [curseforge::Fingerprint]) -> Self {
+        self.fingerprints = fingerprints;
+        self
+    }
+}
+
+impl CurseForgeStore for SliceStore<'_> {
+    fn get_mod(&self, mod_id: i32) -> Option<&curseforge::Mod> {
+        self.mods.iter().find(|m| m.id == mod_id)
+    }
+
+    fn get_file(&self, file_id: i32) -> Option<&curseforge::File> {
+        self.files.iter().find(|f| f.id == file_id)
+    }
+
+    fn files_of(&self, mod_id: i32) -> Vec<&curseforge::File> {
+        self.files.iter().filter(|f| f.mod_id == mod_id).collect()
+    }
+
+    fn fingerprint(&self, fingerprint: i64) -> Lookup<&curseforge::Fingerprint> {
+        self.fingerprints
+            .iter()
+            .find(|f| f.id == fingerprint)
+            .map_or(Lookup::NotSynced, Lookup::Found)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnresolvedReason {
+    /// Neither the mod nor any of its files are stored.
+    ModNotStored,
+    /// No stored file or `latest_files_indexes` entry matches the target.
+    NoCompatibleFile,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurseForgeUnresolved {
+    pub mod_id: i32,
+    /// File id of the dependent, `None` for requested mods.
+    pub required_by: Option<i32>,
+    pub reason: UnresolvedReason,
+}
+
+/// `file_id` of `mod_id` declares `incompatible_mod_id`, which was also
+/// selected, as incompatible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurseForgeConflict {
+    pub mod_id: i32,
+    pub file_id: i32,
+    pub incompatible_mod_id: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CurseForgeSelection {
+    pub mod_id: i32,
+    pub file_id: i32,
+    /// Full file details, when stored or present in `Mod.latest_files`.
+    /// Without them the file's own dependencies cannot be followed.
+    pub file: Option<curseforge::FileInfo>,
+    pub required_by: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CurseForgeResolution {
+    pub files: Vec<CurseForgeSelection>,
+    pub unresolved: Vec<CurseForgeUnresolved>,
+    pub conflicts: Vec<CurseForgeConflict>,
+}
+
+impl CurseForgeResolution {
+    pub fn is_complete(&self) -> bool {
+        self.unresolved.is_empty() && self.conflicts.is_empty()
+    }
+
+    pub fn file_of(&self, mod_id: i32) -> Option<&CurseForgeSelection> {
+        self.files.iter().find(|s| s.mod_id == mod_id)
+    }
+
+    /// Selected file ids whose dependencies are unknown.
+    pub fn unchecked(&self) -> Vec<i32> {
+        self.files
+            .iter()
+            .filter(|s| s.file.is_none())
+            .map(|s| s.file_id)
+            .collect()
+    }
+}
+
+/// Resolves required dependencies over a `CurseForgeStore`, picking for
+/// each mod the newest matching file among its stored files and its
+/// `latest_files_indexes` with the same rule as `Mod::best_file`.
+#[derive(Debug, Clone)]
+pub struct CurseForgeResolver<S> {
+    store: S,
+    target: Target,
+}
+
+impl<S: CurseForgeStore> CurseForgeResolver<S> {
+    pub fn new(store: S, target: Target) -> Self {
+        CurseForgeResolver { store, target }
+    }
+
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// The chosen file id for `mod_id` and its details when known. Stored
+    /// files join the mod's `latest_files_indexes` as extra entries, and
+    /// `Target::best_curseforge_index` picks among those whose file is not
+    /// known to be unavailable.
+    pub fn best_file(&self, mod_id: i32) -> Option<(i32, Option<curseforge::FileInfo>)> {
+        let m = self.store.get_mod(mod_id);
+        let available = |file_id: i32| {
+            let stored = self.store.get_file(file_id).map(|f| f.is_available);
+            let latest = m
+                .and_then(|m| m.latest_files.as_ref())
+                .into_iter()
+                .flatten()
+                .find(|f| f.id == file_id)
+                .map(|f| f.is_available);
+            stored.or(latest).flatten() != Some(false)
+        };
+        let stored: Vec<curseforge::FileIndex> = self
+            .store
+            .files_of(mod_id)
+            .into_iter()
+            .flat_map(|f| f.indexes())
+            .collect();
+        let indexed = m
+            .and_then(|m| m.latest_files_indexes.as_ref())
+            .into_iter()
+            .flatten();
+        let file_id = self
+            .target
+            .best_curseforge_index(indexed.chain(&stored).filter(|i| available(i.file_id)))?
+            .file_id;
+        let info = self.store.get_file(file_id).map(|f| f.info()).or_else(|| {
+            m.and_then(|m| m.latest_files.as_ref())
+                .into_iter()
+                .flatten()
+                .find(|f| f.id == file_id)
+                .cloned()
+        });
+        Some((file_id, info))
+    }
+
+    pub fn resolve(&self, mod_ids: &[i32]) -> CurseForgeResolution {
+        let mut resolution = CurseForgeResolution::default();
+        let mut seen: HashSet<i32> = HashSet::new();
+        let mut queue: VecDeque<(i32, Option<i32>)> =
+            mod_ids.iter().map(|id| (*id, None)).collect();
+
+        while let Some((mod_id, required_by)) = queue.pop_front() {
+            if !seen.insert(mod_id) {
+                continue;
+            }
+            let Some((file_id, file)) = self.best_file(mod_id) else {
+                let stored =
+                    self.store.get_mod(mod_id).is_some() || !self.store.files_of(mod_id).is_empty();
+                resolution.unresolved.push(CurseForgeUnresolved {
+                    mod_id,
+                    required_by,
+                    reason: if stored {
+                        UnresolvedReason::NoCompatibleFile
+                    } else {
+                        UnresolvedReason::ModNotStored
+                    },
+                });
+                continue;
+            };
+            let dependencies = file.iter().flat_map(|f| f.dependencies.iter().flatten());
+            for dep in dependencies {
+                if dep.relation() == Some(curseforge::RelationType::RequiredDependency) {
+                    queue.push_back((dep.mod_id, Some(file_id)));
+                }
+            }
+            resolution.files.push(CurseForgeSelection {
+                mod_id,
+                file_id,
+                file,
+                required_by,
+            });
+        }
+
+        let selected: HashSet<i32> = resolution.files.iter().map(|s| s.mod_id).collect();
+        for selection in &resolution.files {
+            let dependencies = selection
+                .file
+                .iter()
+                .flat_map(|f| f.dependencies.iter().flatten());
+            for dep in dependencies {
+                if dep.relation() == Some(curseforge::RelationType::Incompatible)
+                    && selected.contains(&dep.mod_id)
+                {
+                    resolution.conflicts.push(CurseForgeConflict {
+                        mod_id: selection.mod_id,
+                        file_id: selection.file_id,
+                        incompatible_mod_id: dep.mod_id,
+                    });
+                }
+            }
+        }
+        resolution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_modrinth_resolver() {
+        let versions = r###"
+        [
+            {
+                "_id": "sodium-old",
+                "project_id": "AANobbMI",
+                "version_number": "0.5.8",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [{ "project_id": "P7dR8mSH", "dependency_type": "required" }],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "sodium-new",
+                "project_id": "AANobbMI",
+                "version_number": "0.5.11",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [
+                    { "project_id": "P7dR8mSH", "dependency_type": "required" },
+                    { "project_id": "YL57xq9U", "dependency_type": "optional" },
+                    { "project_id": "MISSING1", "dependency_type": "required" }
+                ],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "sodium-121",
+                "project_id": "AANobbMI",
+                "version_number": "0.6.0",
+                "version_type": "release",
+                "game_versions": ["1.21.1"],
+                "loaders": ["fabric"],
+                "dependencies": [],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "fabric-api",
+                "project_id": "P7dR8mSH",
+                "version_number": "0.92.2",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "optifabric",
+                "project_id": "2USUXDBm",
+                "version_number": "1.14.3",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [
+                    { "version_id": "fabric-api", "dependency_type": "required" },
+                    { "project_id": "AANobbMI", "dependency_type": "incompatible" },
+                    { "version_id": "gone", "dependency_type": "required" }
+                ],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "old-only",
+                "project_id": "OLDONLY1",
+                "version_number": "1.0.0",
+                "version_type": "release",
+                "game_versions": ["1.16.5"],
+                "loaders": ["fabric"],
+                "dependencies": [],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "needs-old",
+                "project_id": "NEEDSOLD",
+                "version_number": "1.0.0",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [{ "version_id": "sodium-old", "dependency_type": "required" }],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "addon-new",
+                "project_id": "ADDON001",
+                "version_number": "2.0.0",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [{ "version_id": "sodium-new", "dependency_type": "incompatible" }],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "addon-old",
+                "project_id": "ADDON001",
+                "version_number": "1.0.0",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "bad-pin",
+                "project_id": "BADPIN01",
+                "version_number": "1.0.0",
+                "version_type": "release",
+                "game_versions": ["1.20.1"],
+                "loaders": ["fabric"],
+                "dependencies": [
+                    { "project_id": "P7dR8mSH", "version_id": "optifabric", "dependency_type": "required" }
+                ],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            }
+        ]
+        "###;
+        let versions: Vec<modrinth::Version> =
+            serde_json::from_str(versions).expect("deserialize versions from json");
+        let target = Target::new(McVersion::parse("1.20.1").expect("version"), "Fabric");
+        let resolver = ModrinthResolver::new(&versions, target);
+
+        let resolution = resolver.resolve(&["AANobbMI", "2USUXDBm", "OLDONLY1"]);
+        let ids: Vec<&str> = resolution.versions.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, ["sodium-new", "optifabric", "fabric-api"]);
+        assert_eq!(
+            resolution.version_of("P7dR8mSH").map(|v| v.id.as_str()),
+            Some("fabric-api")
+        );
+
+        let reasons: Vec<(Option<&str>, &MissingReason)> = resolution
+            .missing
+            .iter()
+            .map(|m| {
+                (
+                    m.project_id.as_deref().or(m.version_id.as_deref()),
+                    &m.reason,
+                )
+            })
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                (Some("OLDONLY1"), &MissingReason::NoCompatibleVersion),
+                (Some("MISSING1"), &MissingReason::ProjectNotStored),
+                (Some("gone"), &MissingReason::VersionNotStored),
+            ]
+        );
+        assert_eq!(
+            resolution.conflicts,
+            [ModrinthConflict::Incompatible {
+                version_id: "optifabric".to_string(),
+                incompatible_with: "sodium-new".to_string(),
+            }]
+        );
+        assert!(!resolution.is_complete());
+
+        let resolution = resolver.resolve(&["AANobbMI", "ADDON001"]);
+        let ids: Vec<&str> = resolution.versions.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, ["sodium-new", "addon-old", "fabric-api"]);
+        assert!(resolution.conflicts.is_empty());
+
+        let resolution = resolver.resolve(&["ADDON001", "AANobbMI"]);
+        let ids: Vec<&str> = resolution.versions.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, ["addon-new", "sodium-old", "fabric-api"]);
+        assert!(resolution.conflicts.is_empty());
+
+        let resolution = resolver.resolve(&["BADPIN01"]);
+        assert_eq!(
+            resolution.missing,
+            [ModrinthMissing {
+                project_id: Some("P7dR8mSH".to_string()),
+                version_id: Some("optifabric".to_string()),
+                required_by: Some("bad-pin".to_string()),
+                reason: MissingReason::PinnedVersionOfOtherProject,
+            }]
+        );
+
+        let pinned = vec![
+            versions[1].clone(),
+            versions[3].clone(),
+            versions[6].clone(),
+            versions[0].clone(),
+        ];
+        let resolver = ModrinthResolver::new(&pinned, resolver.target().clone());
+        let resolution = resolver.resolve(&["AANobbMI", "NEEDSOLD"]);
+        assert!(
+            resolution
+                .conflicts
+                .contains(&ModrinthConflict::VersionMismatch {
+                    project_id: "AANobbMI".to_string(),
+                    selected: "sodium-new".to_string(),
+                    wanted: "sodium-old".to_string(),
+                    required_by: Some("needs-old".to_string()),
+                })
+        );
+    }
+
+    #[test]
+    fn test_curseforge_resolver() {
+        let mods = r###"
+        [
+            {
+                "_id": 100,
+                "slug": "main-mod",
+                "latestFilesIndexes": [
+                    { "gameVersion": "1.20.1", "fileId": 1005, "filename": "main-3.jar", "releaseType": 1, "modLoader": 4 },
+                    { "gameVersion": "1.20.1", "fileId": 1002, "filename": "main-2.jar", "releaseType": 2, "modLoader": 4 },
+                    { "gameVersion": "1.20.1", "fileId": 1001, "filename": "main-1.jar", "releaseType": 1, "modLoader": 4 },
+                    { "gameVersion": "1.20.1", "fileId": 1003, "filename": "main-forge.jar", "releaseType": 1, "modLoader": 1 }
+                ],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "_id": 200,
+                "slug": "library",
+                "latestFiles": [
+                    {
+                        "id": 2001,
+                        "gameId": 432,
+                        "modId": 200,
+                        "releaseType": 1,
+                        "gameVersions": ["Fabric", "1.20.1"],
+                        "dependencies": [{ "modId": 300, "relationType": 5 }]
+                    }
+                ],
+                "latestFilesIndexes": [
+                    { "gameVersion": "1.20.1", "fileId": 2001, "filename": "library.jar", "releaseType": 1, "modLoader": 4 }
+                ],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "_id": 300,
+                "slug": "old-mod",
+                "latestFilesIndexes": [
+                    { "gameVersion": "1.16.5", "fileId": 3001, "filename": "old.jar", "releaseType": 1, "modLoader": 1 }
+                ],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            }
+        ]
+        "###;
+        let files = r###"
+        [
+            {
+                "id": 1001,
+                "gameId": 432,
+                "modId": 100,
+                "releaseType": 1,
+                "gameVersions": ["Fabric", "1.20.1"],
+                "dependencies": [
+                    { "modId": 200, "relationType": 3 },
+                    { "modId": 400, "relationType": 3 },
+                    { "modId": 500, "relationType": 2 }
+                ],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "id": 1005,
+                "gameId": 432,
+                "modId": 100,
+                "isAvailable": false,
+                "releaseType": 1,
+                "gameVersions": ["Fabric", "1.20.1"],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "id": 3002,
+                "gameId": 432,
+                "modId": 300,
+                "releaseType": 1,
+                "gameVersions": ["1.20.1"],
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            }
+        ]
+        "###;
+        let mods: Vec<curseforge::Mod> = serde_json::from_str(mods).expect("deserialize mods");
+        let files: Vec<curseforge::File> = serde_json::from_str(files).expect("deserialize files");
+        let target = Target::new(McVersion::parse("1.20.1").expect("version"), "fabric");
+        let resolver = CurseForgeResolver::new(SliceStore::new(&mods, &files), target);
+
+        let resolution = resolver.resolve(&[100, 300]);
+        let chosen: Vec<(i32, i32)> = resolution
+            .files
+            .iter()
+            .map(|s| (s.mod_id, s.file_id))
+            .collect();
+        // The beta 1002 and the withdrawn 1005 are skipped; 3002 lists no
+        // loader and is accepted.
+        assert_eq!(chosen, [(100, 1001), (300, 3002), (200, 2001)]);
+        assert_eq!(
+            resolution.file_of(200).and_then(|s| s.required_by),
+            Some(1001)
+        );
+        assert_eq!(
+            resolution.unresolved,
+            [CurseForgeUnresolved {
+                mod_id: 400,
+                required_by: Some(1001),
+                reason: UnresolvedReason::ModNotStored,
+            }]
+        );
+        assert_eq!(
+            resolution.conflicts,
+            [CurseForgeConflict {
+                mod_id: 200,
+                file_id: 2001,
+                incompatible_mod_id: 300,
+            }]
+        );
+        assert!(resolution.unchecked().is_empty());
+
+        let target = resolver
+            .target()
+            .clone()
+            .with_min_channel(ReleaseChannel::Beta);
+        let resolver = CurseForgeResolver::new(SliceStore::new(&mods, &files), target);
+        let resolution = resolver.resolve(&[100]);
+        assert_eq!(resolution.files[0].file_id, 1002);
+        assert_eq!(resolution.unchecked(), [1002]);
+
+        let quilt = Target::new(McVersion::parse("1.16.5").expect("version"), "quilt");
+        let resolver = CurseForgeResolver::new(SliceStore::new(&mods, &files), quilt);
+        assert_eq!(
+            resolver.resolve(&[300]).unresolved[0].reason,
+            UnresolvedReason::NoCompatibleFile
+        );
+    }
+}
@@ -1,11 +1,16 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 use bson::serde_helpers::datetime::FromChrono04DateTime;
 
+use crate::curseforge::Mod;
+use crate::modrinth::Project;
+
 #[serde_as]
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ModrinthTranslation {
     #[serde(alias = "_id")]
     pub project_id: String,
@@ -19,7 +24,7 @@ pub struct ModrinthTranslation {
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CurseForgeTranslation {
     #[serde(rename = "modId", alias = "_id")]
     pub mod_id: i32,
@@ -32,6 +37,469 @@ pub struct CurseForgeTranslation {
     pub translated_at: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TranslatedField {
+    Description,
+    Summary,
+}
+
+/// A project with its translation overlaid. Stale (`need_to_update`) or
+/// empty translations are ignored and the original text is used.
+#[derive(Debug, Clone)]
+pub struct Localized<'a, T> {
+    pub value: &'a T,
+    summary: Option<&'a str>,
+    translated: Vec<TranslatedField>,
+}
+
+impl<'a, T> Localized<'a, T> {
+    /// The translated summary, or the original one when untranslated.
+    pub fn summary(&self) -> Option<&'a str> {
+        self.summary
+    }
+
+    pub fn translated_fields(&self) -> &[TranslatedField] {
+        &self.translated
+    }
+
+    pub fn is_translated(&self, field: TranslatedField) -> bool {
+        self.translated.contains(&field)
+    }
+}
+
+impl<T> std::ops::Deref for Localized<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+fn usable(translated: &Option<String>, need_to_update: bool) -> Option<&str> {
+    if need_to_update {
+        return None;
+    }
+    translated.as_deref().filter(|t| !t.trim().is_empty())
+}
+
+impl<'a> Localized<'a, Project> {
+    /// Without a translation record for the project, its embedded
+    /// `translated_description` is used. A stale record does not fall back
+    /// to it, since it carries the same outdated text.
+    pub fn modrinth(project: &'a Project, translation: Option<&'a ModrinthTranslation>) -> Self {
+        let translated = match translation.filter(|t| t.project_id == project.id) {
+            Some(t) => usable(&t.translated, t.need_to_update),
+            None => usable(&project.translated_description, false),
+        };
+        Localized {
+            value: project,
+            summary: translated.or(project.description.as_deref()),
+            translated: translated
+                .map(|_| vec![TranslatedField::Description])
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn modrinth_all(
+        projects: &'a [Project],
+        translations: &'a [ModrinthTranslation],
+    ) -> Vec<Self> {
+        let by_id: HashMap<&str, &ModrinthTranslation> = translations
+            .iter()
+            .map(|t| (t.project_id.as_str(), t))
+            .collect();
+        projects
+            .iter()
+            .map(|p| Localized::modrinth(p, by_id.get(p.id.as_str()).copied()))
+            .collect()
+    }
+}
+
+impl<'a> Localized<'a, Mod> {
+    pub fn curseforge(m: &'a Mod, translation: Option<&'a CurseForgeTranslation>) -> Self {
+        let translated = translation
+            .filter(|t| t.mod_id == m.id)
+            .and_then(|t| usable(&t.translated, t.need_to_update));
+        Localized {
+            value: m,
+            summary: translated.or(m.summary.as_deref()),
+            translated: translated
+                .map(|_| vec![TranslatedField::Summary])
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn curseforge_all(mods: &'a [Mod], translations: &'a [CurseForgeTranslation]) -> Vec<Self> {
+        let by_id: HashMap<i32, &CurseForgeTranslation> =
+            translations.iter().map(|t| (t.mod_id, t)).collect();
+        mods.iter()
+            .map(|m| Localized::curseforge(m, by_id.get(&m.id).copied()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Staleness {
+    Fresh,
+    /// The source text only differs in whitespace; the translation still
+    /// applies.
+    WhitespaceChanged,
+    TextChanged,
+    /// The text could not be compared (no `original` recorded, or the
+    /// project has none), and the project changed after `translated_at`.
+    OutdatedByDate,
+}
+
+impl Staleness {
+    pub fn is_stale(&self) -> bool {
+        matches!(self, Staleness::TextChanged | Staleness::OutdatedByDate)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StalenessCheck<T> {
+    pub staleness: Staleness,
+    /// Whether the project changed after `translated_at`, also when the
+    /// text comparison decided `staleness`.
+    pub modified_since_translation: bool,
+    /// The translation with `original` and `need_to_update` brought in line
+    /// with the project, ready to be written back.
+    pub updated: T,
+}
+
+fn staleness(
+    original: Option<&str>,
+    current: Option<&str>,
+    translated_at: Option<DateTime<Utc>>,
+    modified_at: Option<DateTime<Utc>>,
+) -> (Staleness, bool) {
+    let modified_since = match (translated_at, modified_at) {
+        (Some(translated_at), Some(modified_at)) => modified_at > translated_at,
+        (Some(_), None) => false,
+        (None, _) => true,
+    };
+    let staleness = match (original, current) {
+        (Some(original), Some(current)) if original == current => Staleness::Fresh,
+        (Some(original), Some(current))
+            if original.split_whitespace().eq(current.split_whitespace()) =>
+        {
+            Staleness::WhitespaceChanged
+        }
+        (Some(_), Some(_)) => Staleness::TextChanged,
+        _ if modified_since => Staleness::OutdatedByDate,
+        _ => Staleness::Fresh,
+    };
+    (staleness, modified_since)
+}
+
+/// A text comparison settles the flag either way; a date check can only
+/// raise it.
+fn need_to_update(
+    previous: bool,
+    original: Option<&str>,
+    current: Option<&str>,
+    staleness: Staleness,
+) -> bool {
+    if original.is_some() && current.is_some() {
+        staleness.is_stale()
+    } else {
+        previous || staleness.is_stale()
+    }
+}
+
+impl ModrinthTranslation {
+    /// Compares against the project's current `description`. The returned
+    /// `updated.original` is that description, so writing it back records
+    /// the new source text.
+    pub fn check(&self, project: &Project) -> StalenessCheck<ModrinthTranslation> {
+        let current = project.description.as_deref();
+        let (staleness, modified_since_translation) = staleness(
+            self.original.as_deref(),
+            current,
+            self.translated_at,
+            Some(project.updated),
+        );
+        let mut updated = self.clone();
+        updated.need_to_update = need_to_update(
+            self.need_to_update,
+            self.original.as_deref(),
+            current,
+            staleness,
+        );
+        updated.original = current.map(str::to_string);
+        StalenessCheck {
+            staleness,
+            modified_since_translation,
+            updated,
+        }
+    }
+}
+
+impl CurseForgeTranslation {
+    /// Compares against the mod's current `summary`. The returned
+    /// `updated.original` is that summary, so writing it back records the
+    /// new source text.
+    pub fn check(&self, m: &Mod) -> StalenessCheck<CurseForgeTranslation> {
+        let current = m.summary.as_deref();
+        let (staleness, modified_since_translation) = staleness(
+            self.original.as_deref(),
+            current,
+            self.translated_at,
+            m.date_modified,
+        );
+        let mut updated = self.clone();
+        updated.need_to_update = need_to_update(
+            self.need_to_update,
+            self.original.as_deref(),
+            current,
+            staleness,
+        );
+        updated.original = current.map(str::to_string);
+        StalenessCheck {
+            staleness,
+            modified_since_translation,
+            updated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidLocale(pub String);
+
+impl std::fmt::Display for InvalidLocale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid BCP-47 locale tag {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidLocale {}
+
+/// A BCP-47 language tag (`language[-script][-region][-variant...]`) in
+/// canonical case, e.g. `zh-CN`, `zh-Hant-TW`, `ja`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Locale(String);
+
+impl Locale {
+    pub fn parse(tag: &str) -> Result<Self, InvalidLocale> {
+        let invalid = || InvalidLocale(tag.to_string());
+        let mut parts = tag.split(['-', '_']);
+        let language = parts
+            .next()
+            .filter(|l| (2..=3).contains(&l.len()) && l.chars().all(|c| c.is_ascii_alphabetic()));
+        let mut out = language.ok_or_else(invalid)?.to_ascii_lowercase();
+        // 0: script allowed, 1: region allowed, 2: variants only
+        let mut stage = 0;
+        for part in parts {
+            let alpha = part.chars().all(|c| c.is_ascii_alphabetic());
+            let digit = part.chars().all(|c| c.is_ascii_digit());
+            out.push('-');
+            if stage == 0 && part.len() == 4 && alpha {
+                out.push_str(&part[..1].to_ascii_uppercase());
+                out.push_str(&part[1..].to_ascii_lowercase());
+                stage = 1;
+            } else if stage <= 1 && ((part.len() == 2 && alpha) || (part.len() == 3 && digit)) {
+                out.push_str(&part.to_ascii_uppercase());
+                stage = 2;
+            } else if ((5..=8).contains(&part.len())
+                || (part.len() == 4 && part.starts_with(|c: char| c.is_ascii_digit())))
+                && part.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                out.push_str(&part.to_ascii_lowercase());
+                stage = 2;
+            } else {
+                return Err(invalid());
+            }
+        }
+        Ok(Locale(out))
+    }
+
+    /// The locale of every translation stored before locales were tracked.
+    pub fn zh_cn() -> Self {
+        Locale("zh-CN".to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+
+    /// RFC 4647 lookup order: the tag itself, then with trailing subtags
+    /// removed one at a time (`zh-Hant-TW`, `zh-Hant`, `zh`).
+    pub fn fallbacks(&self) -> Vec<Locale> {
+        let mut tags = vec![self.clone()];
+        let mut tag = self.0.as_str();
+        while let Some(i) = tag.rfind('-') {
+            tag = &tag[..i];
+            tags.push(Locale(tag.to_string()));
+        }
+        tags
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = InvalidLocale;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Locale::parse(s)
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tag = String::deserialize(deserializer)?;
+        Locale::parse(&tag).map_err(serde::de::Error::custom)
+    }
+}
+
+/// What a translation belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum TranslationSubject {
+    ModrinthProject(String),
+    ModrinthVersion(String),
+    ModrinthCategory(String),
+    CurseForgeMod(i32),
+    CurseForgeFile(i32),
+    CurseForgeCategory(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationField {
+    Title,
+    /// Modrinth `description` / CurseForge `summary`.
+    Summary,
+    Body,
+    Changelog,
+    /// Category display names.
+    Name,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct TranslationKey {
+    pub subject: TranslationSubject,
+    pub field: TranslationField,
+    pub locale: Locale,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Translation {
+    #[serde(alias = "_id")]
+    pub key: TranslationKey,
+    pub translated: Option<String>,
+    pub original: Option<String>,
+
+    pub need_to_update: bool,
+
+    #[serde_as(as = "Option<FromChrono04DateTime>")]
+    pub translated_at: Option<DateTime<Utc>>,
+}
+
+impl From<ModrinthTranslation> for Translation {
+    fn from(t: ModrinthTranslation) -> Self {
+        Translation {
+            key: TranslationKey {
+                subject: TranslationSubject::ModrinthProject(t.project_id),
+                field: TranslationField::Summary,
+                locale: Locale::zh_cn(),
+            },
+            translated: t.translated,
+            original: t.original,
+            need_to_update: t.need_to_update,
+            translated_at: t.translated_at,
+        }
+    }
+}
+
+impl From<CurseForgeTranslation> for Translation {
+    fn from(t: CurseForgeTranslation) -> Self {
+        Translation {
+            key: TranslationKey {
+                subject: TranslationSubject::CurseForgeMod(t.mod_id),
+                field: TranslationField::Summary,
+                locale: Locale::zh_cn(),
+            },
+            translated: t.translated,
+            original: t.original,
+            need_to_update: t.need_to_update,
+            translated_at: t.translated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Translations {
+    entries: HashMap<TranslationKey, Translation>,
+}
+
+impl Translations {
+    pub fn new() -> Self {
+        Translations::default()
+    }
+
+    pub fn insert(&mut self, translation: Translation) -> Option<Translation> {
+        self.entries.insert(translation.key.clone(), translation)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get_exact(&self, key: &TranslationKey) -> Option<&Translation> {
+        self.entries.get(key)
+    }
+
+    /// Looks up a translation following `locale`'s fallback chain.
+    pub fn get(
+        &self,
+        subject: &TranslationSubject,
+        field: TranslationField,
+        locale: &Locale,
+    ) -> Option<&Translation> {
+        locale.fallbacks().into_iter().find_map(|locale| {
+            self.entries.get(&TranslationKey {
+                subject: subject.clone(),
+                field,
+                locale,
+            })
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Translation> {
+        self.entries.values()
+    }
+}
+
+impl FromIterator<Translation> for Translations {
+    fn from_iter<I: IntoIterator<Item = Translation>>(iter: I) -> Self {
+        let mut translations = Translations::new();
+        for t in iter {
+            translations.insert(t);
+        }
+        translations
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,4 +543,258 @@ mod tests {
     
     assert_eq!(l.project_id, "Wnxd13zP");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_localized_modrinth_project() {
+        let project = r###"
+        {
+            "_id": "Wnxd13zP",
+            "slug": "clumps",
+            "description": "Clumps XP orbs together to reduce lag",
+            "team": "3TidTIHz",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+        let translation = r###"
+        {
+            "_id": "Wnxd13zP",
+            "original": "Clumps XP orbs together to reduce lag",
+            "translated": "将经验球聚集在一起以减少游戏卡顿。",
+            "translated_at": { "$date": "2025-02-02T08:53:28.684Z" },
+            "need_to_update": false
+        }
+        "###;
+
+        let p: Project = serde_json::from_str(project).expect("deserialize project from json");
+        let mut t: ModrinthTranslation =
+            serde_json::from_str(translation).expect("deserialize translation from json");
+
+        let l = Localized::modrinth(&p, Some(&t));
+        assert_eq!(l.summary(), Some("将经验球聚集在一起以减少游戏卡顿。"));
+        assert_eq!(l.translated_fields(), [TranslatedField::Description]);
+        assert_eq!(l.slug, "clumps");
+
+        t.need_to_update = true;
+        let l = Localized::modrinth(&p, Some(&t));
+        assert_eq!(l.summary(), Some("Clumps XP orbs together to reduce lag"));
+        assert!(!l.is_translated(TranslatedField::Description));
+
+        let all = Localized::modrinth_all(std::slice::from_ref(&p), &[]);
+        assert!(all[0].translated_fields().is_empty());
+
+        let mut p = p;
+        p.translated_description = Some("合并经验球以减少卡顿。".to_string());
+        let l = Localized::modrinth(&p, None);
+        assert_eq!(l.summary(), Some("合并经验球以减少卡顿。"));
+        assert!(l.is_translated(TranslatedField::Description));
+        let l = Localized::modrinth(&p, Some(&t));
+        assert_eq!(l.summary(), Some("Clumps XP orbs together to reduce lag"));
+    }
+
+    #[test]
+    fn test_localized_curseforge_mod() {
+        let m = r###"
+        {
+            "_id": 238222,
+            "slug": "jei",
+            "summary": "View Items and Recipes",
+            "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+        }
+        "###;
+        let translation = r###"
+        {
+            "_id": 238222,
+            "original": "View Items and Recipes",
+            "translated": "查看物品和配方",
+            "translated_at": { "$date": "2025-02-02T10:01:52.805Z" },
+            "need_to_update": false
+        }
+        "###;
+
+        let m: Mod = serde_json::from_str(m).expect("deserialize mod from json");
+        let t: CurseForgeTranslation =
+            serde_json::from_str(translation).expect("deserialize translation from json");
+
+        let all = Localized::curseforge_all(std::slice::from_ref(&m), std::slice::from_ref(&t));
+        assert_eq!(all[0].summary(), Some("查看物品和配方"));
+        assert!(all[0].is_translated(TranslatedField::Summary));
+    }
+
+    #[test]
+    fn test_translation_staleness() {
+        let project = r###"
+        {
+            "_id": "Wnxd13zP",
+            "slug": "clumps",
+            "description": "Clumps XP orbs together to reduce lag",
+            "team": "3TidTIHz",
+            "followers": 0,
+            "published": { "$date": "2022-11-28T17:17:12.676Z" },
+            "updated": { "$date": "2025-05-02T22:27:47.152Z" },
+            "sync_at": { "$date": "2025-06-17T12:44:55.245Z" }
+        }
+        "###;
+        let translation = r###"
+        {
+            "_id": "Wnxd13zP",
+            "original": "Clumps XP orbs together to reduce lag",
+            "translated": "将经验球聚集在一起以减少游戏卡顿。",
+            "translated_at": { "$date": "2025-02-02T08:53:28.684Z" },
+            "need_to_update": false
+        }
+        "###;
+
+        let mut p: Project = serde_json::from_str(project).expect("deserialize project from json");
+        let mut t: ModrinthTranslation =
+            serde_json::from_str(translation).expect("deserialize translation from json");
+
+        let check = t.check(&p);
+        assert_eq!(check.staleness, Staleness::Fresh);
+        assert!(check.modified_since_translation);
+        assert!(!check.updated.need_to_update);
+
+        // Matching text clears a flag left by an earlier check.
+        t.need_to_update = true;
+        assert!(!t.check(&p).updated.need_to_update);
+        t.need_to_update = false;
+
+        p.description = Some("Clumps XP orbs together  to reduce lag\n".to_string());
+        let check = t.check(&p);
+        assert_eq!(check.staleness, Staleness::WhitespaceChanged);
+        assert!(!check.updated.need_to_update);
+        assert_eq!(check.updated.original, p.description);
+
+        p.description = Some("Clumps XP orbs together to reduce server lag".to_string());
+        let check = t.check(&p);
+        assert_eq!(check.staleness, Staleness::TextChanged);
+        assert!(check.updated.need_to_update);
+        assert_eq!(check.updated.translated, t.translated);
+        // The new source text replaces the recorded one.
+        assert_eq!(check.updated.original, p.description);
+
+        t.original = None;
+        let check = t.check(&p);
+        assert_eq!(check.staleness, Staleness::OutdatedByDate);
+        assert!(check.updated.need_to_update);
+
+        t.original = p.description.clone();
+        p.description = None;
+        let check = t.check(&p);
+        assert_eq!(check.staleness, Staleness::OutdatedByDate);
+        assert_eq!(check.updated.original, None);
+    }
+
+    #[test]
+    fn test_curseforge_translation_staleness() {
+        let m = r###"
+        {
+            "_id": 238222,
+            "slug": "jei",
+            "summary": "View Items and Recipes!",
+            "dateModified": { "$date": "2025-01-01T00:00:00Z" },
+            "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+        }
+        "###;
+        let translation = r###"
+        {
+            "_id": 238222,
+            "original": null,
+            "translated": "查看物品和配方",
+            "translated_at": { "$date": "2025-02-02T10:01:52.805Z" },
+            "need_to_update": false
+        }
+        "###;
+
+        let m: Mod = serde_json::from_str(m).expect("deserialize mod from json");
+        let t: CurseForgeTranslation =
+            serde_json::from_str(translation).expect("deserialize translation from json");
+
+        let check = t.check(&m);
+        assert_eq!(check.staleness, Staleness::Fresh);
+        assert!(!check.modified_since_translation);
+        assert_eq!(
+            check.updated.original.as_deref(),
+            Some("View Items and Recipes!")
+        );
+    }
+
+    #[test]
+    fn test_locale_parse() {
+        assert_eq!(Locale::parse("zh-cn").unwrap().as_str(), "zh-CN");
+        assert_eq!(Locale::parse("zh_hant_tw").unwrap().as_str(), "zh-Hant-TW");
+        assert_eq!(Locale::parse("ja").unwrap().language(), "ja");
+        assert_eq!(Locale::parse("es-419").unwrap().as_str(), "es-419");
+        assert!(Locale::parse("").is_err());
+        assert!(Locale::parse("chinese").is_err());
+        assert!(Locale::parse("zh-CN-Hant").is_err());
+        assert!(Locale::parse("en-1$$$").is_err());
+        assert_eq!(Locale::parse("de-1996").unwrap().as_str(), "de-1996");
+
+        let fallbacks: Vec<String> = Locale::parse("zh-Hant-TW")
+            .unwrap()
+            .fallbacks()
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(fallbacks, ["zh-Hant-TW", "zh-Hant", "zh"]);
+    }
+
+    #[test]
+    fn test_multi_language_translation_model() {
+        let json = r###"
+        {
+            "_id": {
+                "subject": { "type": "modrinth_version", "id": "1ZHtT6Xo" },
+                "field": "changelog",
+                "locale": "ja"
+            },
+            "original": "Port to 1.21.4",
+            "translated": "1.21.4 に移植",
+            "translated_at": {
+                "$date": "2025-02-02T08:53:28.684Z"
+            },
+            "need_to_update": false
+        }
+        "###;
+
+        let t: Translation = serde_json::from_str(json).expect("deserialize translation from json");
+        assert_eq!(
+            t.key.subject,
+            TranslationSubject::ModrinthVersion("1ZHtT6Xo".to_string())
+        );
+        assert_eq!(t.key.field, TranslationField::Changelog);
+
+        let legacy = r###"
+        {
+            "_id": 238222,
+            "original": "View Items and Recipes",
+            "translated": "查看物品和配方",
+            "translated_at": null,
+            "need_to_update": false
+        }
+        "###;
+        let legacy: CurseForgeTranslation =
+            serde_json::from_str(legacy).expect("deserialize translation from json");
+        let migrated = Translation::from(legacy);
+        assert_eq!(migrated.key.locale, Locale::zh_cn());
+        assert_eq!(migrated.key.field, TranslationField::Summary);
+
+        let translations: Translations = [t, migrated].into_iter().collect();
+        let subject = TranslationSubject::CurseForgeMod(238222);
+        let zh_hans = Locale::parse("zh-CN").unwrap();
+        let found = translations.get(&subject, TranslationField::Summary, &zh_hans);
+        assert_eq!(
+            found.and_then(|t| t.translated.as_deref()),
+            Some("查看物品和配方")
+        );
+        let zh_tw = Locale::parse("zh-TW").unwrap();
+        assert!(
+            translations
+                .get(&subject, TranslationField::Summary, &zh_tw)
+                .is_none()
+        );
+    }
+}
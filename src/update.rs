@@ -0,0 +1,373 @@
+use std::cmp::Ordering;
+
+use crate::curseforge;
+use crate::modrinth::{self, FileHash};
+use crate::platform::ReleaseChannel;
+use crate::resolver::{CurseForgeResolver, CurseForgeStore, ModrinthResolver, Target};
+
+/// How a locally installed file is identified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Installed {
+    /// SHA-1 or SHA-512 of the file, looked up on Modrinth.
+    Modrinth(FileHash),
+    /// `curseforge::murmur2_fingerprint` of the file.
+    CurseForge(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateAction {
+    /// The installed release is the newest compatible one.
+    UpToDate,
+    /// A newer compatible release exists. An older release on a more
+    /// stable channel is never offered as an update.
+    Update,
+    /// The installed release is for another game version or loader;
+    /// `latest` matches the target.
+    Replace,
+    /// Nothing of this project matches the target.
+    NoCompatibleRelease,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModrinthUpdate<'a> {
+    pub hash: FileHash,
+    pub installed: &'a modrinth::Version,
+    pub installed_file: &'a modrinth::FileInfo,
+    pub latest: Option<&'a modrinth::Version>,
+    pub action: UpdateAction,
+}
+
+impl<'a> ModrinthUpdate<'a> {
+    /// The file to download for `latest`.
+    pub fn latest_file(&self) -> Option<&'a modrinth::FileInfo> {
+        self.latest.and_then(|v| v.primary_file())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CurseForgeUpdate {
+    pub fingerprint: i64,
+    pub installed: curseforge::FileInfo,
+    pub latest_file_id: Option<i32>,
+    /// Details of the latest file when they are stored.
+    pub latest: Option<curseforge::FileInfo>,
+    pub action: UpdateAction,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdatePlan<'a> {
+    pub modrinth: Vec<ModrinthUpdate<'a>>,
+    pub curseforge: Vec<CurseForgeUpdate>,
+    /// Files that match nothing stored.
+    pub unknown: Vec<Installed>,
+}
+
+/// `target` with every release channel allowed, so that an installed beta
+/// is not replaced by an older release.
+fn any_channel(target: &Target) -> Target {
+    target.clone().with_min_channel(ReleaseChannel::Alpha)
+}
+
+fn wants_change(action: UpdateAction) -> bool {
+    matches!(action, UpdateAction::Update | UpdateAction::Replace)
+}
+
+impl UpdatePlan<'_> {
+    pub fn has_updates(&self) -> bool {
+        self.modrinth.iter().any(|u| wants_change(u.action))
+            || self.curseforge.iter().any(|u| wants_change(u.action))
+    }
+
+    pub fn modrinth_updates(&self) -> impl Iterator<Item = &ModrinthUpdate<'_>> {
+        self.modrinth.iter().filter(|u| wants_change(u.action))
+    }
+
+    pub fn curseforge_updates(&self) -> impl Iterator<Item = &CurseForgeUpdate> {
+        self.curseforge.iter().filter(|u| wants_change(u.action))
+    }
+}
+
+/// Finds newer releases of installed files among stored Modrinth versions
+/// and CurseForge documents, using the same selection rules as the
+/// dependency resolvers.
+pub struct UpdateChecker<'a, S> {
+    versions: &'a [modrinth::Version],
+    modrinth: ModrinthResolver<'a>,
+    curseforge: CurseForgeResolver<S>,
+}
+
+impl<'a, S: CurseForgeStore> UpdateChecker<'a, S> {
+    pub fn new(versions: &'a [modrinth::Version], store: S, target: Target) -> Self {
+        UpdateChecker {
+            versions,
+            modrinth: ModrinthResolver::new(versions, target.clone()),
+            curseforge: CurseForgeResolver::new(store, target),
+        }
+    }
+
+    pub fn check_modrinth(&self, hash: &FileHash) -> Option<ModrinthUpdate<'a>> {
+        let (installed, installed_file) = self
+            .versions
+            .iter()
+            .find_map(|v| v.file_by_hash(hash).map(|f| (v, f)))?;
+        let latest = self.modrinth.newest(&installed.project_id);
+        let action = match latest {
+            None => UpdateAction::NoCompatibleRelease,
+            Some(_) if !any_channel(self.modrinth.target()).accepts_modrinth(installed) => {
+                UpdateAction::Replace
+            }
+            Some(l) if l.id != installed.id && l.cmp_release(installed) == Ordering::Greater => {
+                UpdateAction::Update
+            }
+            Some(_) => UpdateAction::UpToDate,
+        };
+        Some(ModrinthUpdate {
+            hash: hash.clone(),
+            installed,
+            installed_file,
+            latest,
+            action,
+        })
+    }
+
+    /// Looks the fingerprint up in the synced fingerprint matches; files
+    /// missing upstream or never synced yield `None`.
+    pub fn check_curseforge(&self, fingerprint: i64) -> Option<CurseForgeUpdate> {
+        let record = self.curseforge.store().fingerprint(fingerprint).found()?;
+        let installed = curseforge::File::from_info(record.file.clone(), record.sync_at);
+        let latest = self.curseforge.best_file(installed.mod_id);
+        let newer = |id: i32, info: &Option<curseforge::FileInfo>| match info {
+            Some(info) => {
+                let file = curseforge::File::from_info(info.clone(), record.sync_at);
+                file.cmp_release(&installed) == Ordering::Greater
+            }
+            // File ids grow with upload order.
+            None => id > installed.id,
+        };
+        let action = match &latest {
+            None => UpdateAction::NoCompatibleRelease,
+            Some(_) if !any_channel(self.curseforge.target()).accepts_curseforge(&installed) => {
+                UpdateAction::Replace
+            }
+            Some((id, info)) if *id != installed.id && newer(*id, info) => UpdateAction::Update,
+            Some(_) => UpdateAction::UpToDate,
+        };
+        let (latest_file_id, latest) = match latest {
+            Some((id, info)) => (Some(id), info),
+            None => (None, None),
+        };
+        Some(CurseForgeUpdate {
+            fingerprint,
+            installed: record.file.clone(),
+            latest_file_id,
+            latest,
+            action,
+        })
+    }
+
+    pub fn plan(&self, installed: &[Installed]) -> UpdatePlan<'a> {
+        let mut plan = UpdatePlan::default();
+        for item in installed {
+            match item {
+                Installed::Modrinth(hash) => match self.check_modrinth(hash) {
+                    Some(update) => plan.modrinth.push(update),
+                    None => plan.unknown.push(item.clone()),
+                },
+                Installed::CurseForge(fingerprint) => match self.check_curseforge(*fingerprint) {
+                    Some(update) => plan.curseforge.push(update),
+                    None => plan.unknown.push(item.clone()),
+                },
+            }
+        }
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minecraft::McVersion;
+    use crate::resolver::SliceStore;
+    use serde_json;
+
+    #[test]
+    fn test_update_plan() {
+        let versions = r###"
+        [
+            {
+                "_id": "old",
+                "project_id": "Wnxd13zP",
+                "version_number": "22.0.0.1",
+                "version_type": "release",
+                "game_versions": ["1.21.4"],
+                "loaders": ["fabric"],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2024-12-22T23:43:33Z" },
+                "downloads": 0,
+                "files": [
+                    {
+                        "hashes": {
+                            "sha512": "86909659af2f4b481ae9b230996e86658e622424e28b808d069144bf116bf47191df74cfec8b88bbc37ec9ad8cf5a4a24a0f21b39d6c456132331881c8575aeb",
+                            "sha1": "d1f522452cfa1286349525ccace065a8ec7eb940"
+                        },
+                        "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/old/Clumps-fabric-1.21.4-22.0.0.1.jar",
+                        "filename": "Clumps-fabric-1.21.4-22.0.0.1.jar",
+                        "primary": true,
+                        "size": 20721,
+                        "file_type": null
+                    }
+                ],
+                "sync_at": { "$date": "2024-12-31T15:01:35Z" }
+            },
+            {
+                "_id": "beta",
+                "project_id": "Wnxd13zP",
+                "version_number": "22.0.0.3",
+                "version_type": "beta",
+                "game_versions": ["1.21.4"],
+                "loaders": ["fabric"],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2025-02-01T00:00:00Z" },
+                "downloads": 0,
+                "files": [],
+                "sync_at": { "$date": "2025-02-01T00:00:00Z" }
+            },
+            {
+                "_id": "new",
+                "project_id": "Wnxd13zP",
+                "version_number": "22.0.0.2",
+                "version_type": "release",
+                "game_versions": ["1.21.4"],
+                "loaders": ["fabric"],
+                "author_id": "l45nT5ov",
+                "date_published": { "$date": "2025-01-10T00:00:00Z" },
+                "downloads": 0,
+                "files": [
+                    {
+                        "hashes": {
+                            "sha512": "00909659af2f4b481ae9b230996e86658e622424e28b808d069144bf116bf47191df74cfec8b88bbc37ec9ad8cf5a4a24a0f21b39d6c456132331881c8575aeb",
+                            "sha1": "00f522452cfa1286349525ccace065a8ec7eb940"
+                        },
+                        "url": "https://cdn.modrinth.com/data/Wnxd13zP/versions/new/Clumps-fabric-1.21.4-22.0.0.2.jar",
+                        "filename": "Clumps-fabric-1.21.4-22.0.0.2.jar",
+                        "primary": true,
+                        "size": 20721,
+                        "file_type": null
+                    }
+                ],
+                "sync_at": { "$date": "2025-01-10T00:00:00Z" }
+            }
+        ]
+        "###;
+        let files = r###"
+        [
+            {
+                "id": 3872689,
+                "gameId": 432,
+                "modId": 594678,
+                "releaseType": 1,
+                "gameVersions": ["Forge", "1.19"],
+                "fileFingerprint": 2194437269,
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "id": 3913840,
+                "gameId": 432,
+                "modId": 594678,
+                "releaseType": 1,
+                "displayName": "hats-and-cosmetics-1.2.2-1.19.1",
+                "gameVersions": ["Forge", "1.19.1"],
+                "fileFingerprint": 1234,
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            },
+            {
+                "id": 3913841,
+                "gameId": 432,
+                "modId": 594678,
+                "releaseType": 2,
+                "displayName": "hats-and-cosmetics-1.3.0-1.19.1",
+                "gameVersions": ["Forge", "1.19.1"],
+                "fileFingerprint": 5678,
+                "sync_at": { "$date": "2025-06-17T10:46:29.504Z" }
+            }
+        ]
+        "###;
+        let versions: Vec<modrinth::Version> =
+            serde_json::from_str(versions).expect("deserialize versions");
+        let files: Vec<curseforge::File> = serde_json::from_str(files).expect("deserialize files");
+        let fingerprints: Vec<curseforge::Fingerprint> = files
+            .iter()
+            .map(|f| curseforge::Fingerprint {
+                id: f.file_fingerprint.expect("fingerprint"),
+                file: f.info(),
+                latest_files: Vec::new(),
+                sync_at: f.sync_at,
+            })
+            .collect();
+        let store = SliceStore::new(&[], &files).with_fingerprints(&fingerprints);
+
+        let target = Target::new(McVersion::parse("1.21.4").expect("version"), "fabric");
+        let checker = UpdateChecker::new(&versions, store, target);
+        let sha1 = FileHash::parse("d1f522452cfa1286349525ccace065a8ec7eb940").expect("hash");
+        let plan = checker.plan(&[
+            Installed::Modrinth(sha1),
+            Installed::CurseForge(2194437269),
+            Installed::CurseForge(99),
+        ]);
+
+        let update = &plan.modrinth[0];
+        assert_eq!(update.installed.id, "old");
+        assert_eq!(update.action, UpdateAction::Update);
+        assert_eq!(
+            update.latest_file().map(|f| f.filename.as_str()),
+            Some("Clumps-fabric-1.21.4-22.0.0.2.jar")
+        );
+        assert_eq!(plan.curseforge[0].action, UpdateAction::NoCompatibleRelease);
+        assert_eq!(plan.unknown, [Installed::CurseForge(99)]);
+        assert!(plan.has_updates());
+
+        let target = Target::new(McVersion::parse("1.19.1").expect("version"), "forge");
+        let checker = UpdateChecker::new(&versions, store, target);
+        let update = checker.check_curseforge(2194437269).expect("installed");
+        assert_eq!(update.action, UpdateAction::Replace);
+        assert_eq!(update.latest_file_id, Some(3913840));
+        let current = checker.check_curseforge(1234).expect("installed");
+        assert_eq!(current.action, UpdateAction::UpToDate);
+        // The installed beta is newer than the latest release: no downgrade.
+        let beta = checker.check_curseforge(5678).expect("installed");
+        assert_eq!(beta.latest_file_id, Some(3913840));
+        assert_eq!(beta.action, UpdateAction::UpToDate);
+
+        let target = Target::new(McVersion::parse("1.21.4").expect("version"), "fabric")
+            .with_min_channel(ReleaseChannel::Beta);
+        let checker = UpdateChecker::new(&versions, store, target);
+        let sha512 = versions[2].files[0]
+            .file_hash(modrinth::HashAlgorithm::Sha512)
+            .expect("hash");
+        let update = checker.check_modrinth(&sha512).expect("installed");
+        assert_eq!(update.latest.map(|v| v.id.as_str()), Some("beta"));
+        assert_eq!(update.action, UpdateAction::Update);
+    }
+
+    #[test]
+    fn test_murmur2_fingerprint() {
+        // Reference MurmurHash2 values for seed 1 over the stripped bytes.
+        assert_eq!(curseforge::murmur2_fingerprint(b""), 0x5bd15e36);
+        assert_eq!(curseforge::murmur2_fingerprint(b"abc"), 1621425345);
+        assert_eq!(curseforge::murmur2_fingerprint(b"abcd"), 3376380438);
+        assert_eq!(curseforge::murmur2_fingerprint(b"a b\tc\r\nd"), 3376380438);
+        assert_eq!(
+            curseforge::murmur2_fingerprint(b"The quick brown fox jumps over the lazy dog"),
+            3751777527
+        );
+        assert_eq!(curseforge::murmur2_fingerprint(b" \t\r\n"), 0x5bd15e36);
+        assert_eq!(
+            curseforge::murmur2_fingerprint(b"hello world\n"),
+            curseforge::murmur2_fingerprint(b"helloworld")
+        );
+        assert_ne!(
+            curseforge::murmur2_fingerprint(b"helloworld"),
+            curseforge::murmur2_fingerprint(b"helloworle")
+        );
+    }
+}